@@ -11,6 +11,7 @@
 //! - **Enum-based polymorphism** ([`Mammal`]) for type-safe collections
 //! - **Hidden ECS implementation** through the Gateway pattern
 //! - **Zero-cost abstractions** with compile-time type safety
+//! - **Ready-made fixtures** ([`testing`]) for doctests and examples
 //!
 //! # Example
 //!
@@ -61,5 +62,11 @@
 //! [`Mammal`]: pet_module::Mammal
 
 pub mod pet_module;
+pub mod testing;
 
 pub use pet_module::{Cat, Dog, Mammal, PetState};
+
+/// The ECS library behind [`PetState`], re-exported so code using
+/// [`PetState::with_world`] builds against the same version.
+#[cfg(feature = "world-access")]
+pub use specs;
@@ -28,63 +28,75 @@ pub mod pet_module;
 
 use pet_module::*;
 
-fn main() {
-    println!("=== ECS-Based Inheritance Pattern Demonstration ===\n");
-
-    println!("Step 1: Creating PetState and adding pets...");
-
-    let ps = {
-        let ps = PetState::new();
-
-        let (ps, _shippen_id) = Dog::create(ps, "Shippen", "gray", "schnauzer", true, 2.0, 42);
+/// Rows printed per listing; the sample dataset is too large to show in full.
+const PREVIEW: usize = 5;
 
-        let (ps, _sophie_id) = Dog::create(ps, "Sophie", "blond", "schnauzer", true, 2.0, 56);
-
-        let (ps, _waterloo_id) = Dog::create(ps, "Waterloo", "blond", "labrador", true, 12.0, 4);
-
-        let (ps, _berlioz_id) = Cat::create(ps, "Berlioz", "black", "shorthair", true, true, false);
+/// Notes how many rows of a listing were left out by [`PREVIEW`].
+fn print_remaining(total: usize) {
+    if total > PREVIEW {
+        println!("... and {} more", total - PREVIEW);
+    }
+}
 
-        let (ps, _simba_id) = Cat::create(ps, "Simba", "blond", "shorthair", true, true, true);
+fn main() {
+    println!("=== ECS-Based Inheritance Pattern Demonstration ===\n");
 
-        println!("Created 3 dogs and 2 cats\n");
+    println!("Step 1: Loading the sample dataset and adding a pet...");
 
-        ps
-    };
+    let (ps, _sophie_id) = Dog::create(
+        PetState::with_sample_data(),
+        "Sophie",
+        "golden",
+        "schnauzer",
+        true,
+        2.0,
+        56,
+    );
+    println!("Loaded the embedded sample data and created Sophie\n");
 
     println!("Step 2: Querying all dogs (simulates JOIN across Pet, Mammal, Dog tables)");
     println!("{}", "-".repeat(70));
 
     let dogs = ps.get_all_dogs();
-    for dog in &dogs {
+    for dog in dogs.iter().take(PREVIEW) {
         println!(
             "Dog: {} ({}), Breed: {}, Commands Known: {}",
             dog.pet.name,
             dog.mammal.hair_color,
-            dog.mammal.breed,
+            dog.mammal.breed.as_deref().unwrap_or("unknown"),
             dog.dog_specific.num_commands_known
         );
     }
+    print_remaining(dogs.len());
     println!();
 
     println!("Step 3: Querying all cats");
     println!("{}", "-".repeat(70));
 
     let cats = ps.get_all_cats();
-    for cat in &cats {
+    for cat in cats.iter().take(PREVIEW) {
         println!(
             "Cat: {} ({}), Sits on Keyboard: {}",
             cat.pet.name, cat.mammal.hair_color, cat.cat_specific.sits_on_keyboard
         );
     }
+    print_remaining(cats.len());
     println!();
 
     println!("Step 4: Querying all mammals (polymorphic collection via enum)");
     println!("{}", "-".repeat(70));
 
     let all_mammals = ps.get_all_mammals();
-    println!("Total mammals: {}", all_mammals.len());
-
-    for mammal in &all_mammals {
+    let stats = ps.summary();
+    println!(
+        "Total mammals: {} ({} dogs, {} cats, {} breeds)",
+        stats.mammals(),
+        stats.dogs,
+        stats.cats,
+        stats.distinct_breeds
+    );
+
+    for mammal in all_mammals.iter().take(PREVIEW) {
         // Access common data through the enum's methods
         let pet_data = mammal.pet_data();
         let mammal_data = mammal.mammal_data();
@@ -92,31 +104,23 @@ fn main() {
 
         println!(
             "{}: {} ({}) - Says: {}",
-            match mammal {
-                Mammal::Dog(_) => "Dog",
-                Mammal::Cat(_) => "Cat",
-            },
+            mammal.species().name(),
             pet_data.name,
             mammal_data.hair_color,
             sound
         );
     }
+    print_remaining(all_mammals.len());
     println!();
 
-    println!("Step 5: Filtered query - All blond mammals");
+    println!("Step 5: Filtered query - All golden mammals");
     println!("{}", "-".repeat(70));
 
-    let blond_mammals = ps.get_mammals_by_hair_color("blond");
-    for mammal in &blond_mammals {
-        println!(
-            "{}: {}",
-            match mammal {
-                Mammal::Dog(_) => "Dog",
-                Mammal::Cat(_) => "Cat",
-            },
-            mammal.pet_data().name
-        );
+    let golden_mammals = ps.get_mammals_by_hair_color("golden");
+    for mammal in golden_mammals.iter().take(PREVIEW) {
+        println!("{}: {}", mammal.species().name(), mammal.pet_data().name);
     }
+    print_remaining(golden_mammals.len());
     println!();
 
     println!("Step 6: Demonstrating composition structure");
@@ -126,7 +130,7 @@ fn main() {
         println!("Accessing dog data through composition:");
         println!("  dog.pet.uuid = {}", dog.pet.uuid);
         println!("  dog.pet.name = {}", dog.pet.name);
-        println!("  dog.mammal.breed = {}", dog.mammal.breed);
+        println!("  dog.mammal.breed = {:?}", dog.mammal.breed);
         println!("  dog.mammal.has_hair = {}", dog.mammal.has_hair);
         println!(
             "  dog.dog_specific.tail_length = {}",
@@ -170,12 +174,12 @@ fn main() {
     println!("{}", "-".repeat(70));
 
     println!("Dogs:");
-    for dog in &dogs {
+    for dog in dogs.iter().take(3) {
         println!("  {}", dog);
     }
 
     println!("\nCats:");
-    for cat in &cats {
+    for cat in cats.iter().take(3) {
         println!("  {}", cat);
     }
 
@@ -202,6 +206,12 @@ fn main() {
                     cat.pet.name, cat.cat_specific.sits_on_keyboard
                 );
             }
+            Mammal::Hamster(hamster) => {
+                println!(
+                    "This is a hamster named {} - Hoards food: {}",
+                    hamster.pet.name, hamster.hamster_specific.hoards_food
+                );
+            }
         }
     }
     println!();
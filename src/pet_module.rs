@@ -34,11 +34,15 @@
 //! assert_eq!(dogs[0].pet.name, "Buddy");
 //! ```
 
-pub use crate::pet_module::pet_state::PetState;
+pub use crate::pet_module::pet_state::{
+    IdGenerator, MammalQuery, PetEntry, PetQuery, PetState, PetStateBuilder, PetStateOptions, Spec,
+    UuidV4Generator,
+};
 
 mod pet_state;
 
 use std::fmt;
+use std::ops::RangeInclusive;
 
 // ============================================================================
 // Data Structures using Composition (addresses field duplication concern)
@@ -80,9 +84,25 @@ pub struct PetData {
 /// # Fields
 ///
 /// * `hair_color` - Color of the mammal's hair/fur
-/// * `breed` - Breed classification
+/// * `breed` - Breed classification, `None` when unknown (a `NULL` column)
 /// * `has_hair` - Whether the mammal has hair (true for most mammals)
 ///
+/// # Optional Columns
+///
+/// Nullable columns map to `Option` fields all the way down: the component,
+/// this data struct, and the constructors (via [`IntoNullable`]) all carry
+/// `Option<String>` for `breed`.
+///
+/// ```sql
+/// CREATE TABLE Mammal (
+///     id UUID PRIMARY KEY,
+///     pet_id UUID REFERENCES Pet(id),
+///     hair_color VARCHAR NOT NULL,
+///     breed VARCHAR NULL,
+///     has_hair BOOLEAN NOT NULL
+/// );
+/// ```
+///
 /// # Example
 ///
 /// ```
@@ -90,30 +110,83 @@ pub struct PetData {
 ///
 /// let mammal_data = MammalData {
 ///     hair_color: "golden".to_string(),
-///     breed: "retriever".to_string(),
+///     breed: Some("retriever".to_string()),
 ///     has_hair: true,
 /// };
 ///
 /// assert!(mammal_data.has_hair);
 /// ```
+///
+/// `hair_color` is stored trimmed and lowercased, so values read back from a
+/// [`PetState`] are always in that form.
 #[derive(Debug, Clone, PartialEq)]
 pub struct MammalData {
     pub hair_color: String,
-    pub breed: String,
+    pub breed: Option<String>,
     pub has_hair: bool,
 }
 
-/// Data specific to reptiles.
+/// Conversion into the value of a nullable column.
 ///
-/// Maps to the `Reptile` table in Class Table Inheritance pattern.
-/// Included for demonstration purposes and student exercises.
+/// Lets constructors accept a plain value for the common case while still
+/// allowing an explicit `None` (SQL `NULL`), in the spirit of `impl Into<String>`.
+/// Only owned `Option<String>` is implemented, so a bare `None` always
+/// resolves; pass a borrowed optional through [`nullable`].
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let breed: Option<String> = Some("collie".to_string());
+///
+/// let ps = PetState::new();
+/// let (ps, _) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+/// let (ps, _) = Dog::create(ps, "Stray", "brown", None, true, 9.0, 2);
+/// let (ps, _) = Dog::create(ps, "Lassie", "sable", nullable(breed.as_deref()), true, 12.0, 30);
+///
+/// let dogs = ps.get_all_dogs();
+/// assert_eq!(dogs[0].mammal.breed.as_deref(), Some("boxer"));
+/// assert_eq!(dogs[1].mammal.breed, None);
+/// assert_eq!(dogs[2].mammal.breed, breed);
+/// ```
+pub trait IntoNullable<T> {
+    /// Converts `self` into the column value, `None` meaning `NULL`.
+    fn into_nullable(self) -> Option<T>;
+}
+
+impl IntoNullable<String> for &str {
+    fn into_nullable(self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl IntoNullable<String> for String {
+    fn into_nullable(self) -> Option<String> {
+        Some(self)
+    }
+}
+
+impl IntoNullable<String> for Option<String> {
+    fn into_nullable(self) -> Option<String> {
+        self
+    }
+}
+
+/// Converts a borrowed nullable string into its owned column value.
 ///
-/// # Note
+/// A second `Option` impl of [`IntoNullable`] would make a bare `None`
+/// ambiguous, so borrowed values go through this helper instead.
+pub fn nullable(value: Option<&str>) -> Option<String> {
+    value.map(str::to_string)
+}
+
+/// Data specific to reptiles.
 ///
-/// This type is not currently used in the main implementation but demonstrates
-/// how to extend the hierarchy to support multiple animal types.
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+/// Maps to the `Reptile` table in Class Table Inheritance pattern.
+/// The reptile counterpart of [`MammalData`]: a second branch of the
+/// hierarchy under the same `Pet` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReptileData {
     pub scale_color: String,
     pub is_poisonous: bool,
@@ -165,6 +238,53 @@ pub struct CatData {
     pub sits_on_keyboard: bool,
 }
 
+/// Hamster-specific data.
+///
+/// Maps to the `Hamster` table in Class Table Inheritance pattern.
+/// In a database, this would have a foreign key to the `Mammal` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HamsterData {
+    pub is_dwarf: bool,
+    pub hoards_food: bool,
+}
+
+/// Turtle-specific data.
+///
+/// Maps to the `Turtle` table in Class Table Inheritance pattern.
+/// In a database, this would have a foreign key to the `Reptile` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurtleData {
+    pub is_aquatic: bool,
+    pub is_soft_shelled: bool,
+}
+
+/// Snake-specific data.
+///
+/// Maps to the `Snake` table in Class Table Inheritance pattern.
+/// In a database, this would have a foreign key to the `Reptile` table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnakeData {
+    pub length: f64,
+}
+
+/// Borrowed view of a `Pet` row.
+///
+/// Handed out by visitors such as [`PetState::for_each_dog`], which read
+/// component data in place instead of cloning it into a [`PetData`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PetView<'a> {
+    pub uuid: &'a str,
+    pub name: &'a str,
+}
+
+/// Borrowed view of a `Mammal` row; see [`PetView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MammalView<'a> {
+    pub hair_color: &'a str,
+    pub breed: Option<&'a str>,
+    pub has_hair: bool,
+}
+
 // ============================================================================
 // Domain Model: Concrete Types using Composition
 // ============================================================================
@@ -179,7 +299,7 @@ pub struct CatData {
 ///
 /// This maps to a Class Table Inheritance pattern with three tables:
 /// - `Pet` table (uuid, name)
-/// - `Mammal` table (pet_id FK, hair_color, breed, has_hair)
+/// - `Mammal` table (pet_id FK, hair_color, breed NULL, has_hair)
 /// - `Dog` table (mammal_id FK, tail_length, num_commands_known)
 ///
 /// # Example
@@ -200,7 +320,7 @@ pub struct CatData {
 ///
 /// let dogs = ps.get_all_dogs();
 /// assert_eq!(dogs[0].pet.name, "Max");
-/// assert_eq!(dogs[0].mammal.breed, "german_shepherd");
+/// assert_eq!(dogs[0].mammal.breed.as_deref(), Some("german_shepherd"));
 /// assert_eq!(dogs[0].dog_specific.num_commands_known, 25);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
@@ -242,6 +362,220 @@ pub struct Cat {
     pub cat_specific: CatData,
 }
 
+/// A hamster entity, the third leaf of the mammal branch.
+///
+/// Composed like [`Dog`] and [`Cat`] from the Pet, Mammal and Hamster rows.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (ps, _) = Hamster::create(ps, "Nibbles", "golden", "syrian", true, false, true);
+///
+/// let hamsters = ps.get_all_hamsters();
+/// assert_eq!(hamsters[0].pet.name, "Nibbles");
+/// assert!(hamsters[0].hamster_specific.hoards_food);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hamster {
+    pub pet: PetData,
+    pub mammal: MammalData,
+    pub hamster_specific: HamsterData,
+}
+
+/// A turtle entity, the first leaf of the reptile branch.
+///
+/// Composed exactly like [`Dog`] and [`Cat`], with [`ReptileData`] in place
+/// of [`MammalData`].
+///
+/// # Database Mapping
+///
+/// - `Pet` table (uuid, name)
+/// - `Reptile` table (pet_id FK, scale_color, is_poisonous)
+/// - `Turtle` table (reptile_id FK, is_aquatic, is_soft_shelled)
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (ps, _) = Turtle::create(ps, "Shelly", "olive", false, true, false);
+///
+/// let turtles = ps.get_all_turtles();
+/// assert_eq!(turtles[0].pet.name, "Shelly");
+/// assert_eq!(turtles[0].reptile.scale_color, "olive");
+/// assert!(turtles[0].turtle_specific.is_aquatic);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Turtle {
+    pub pet: PetData,
+    pub reptile: ReptileData,
+    pub turtle_specific: TurtleData,
+}
+
+/// A snake entity, the second leaf of the reptile branch.
+///
+/// Like [`Turtle`], composed from the Pet and Reptile rows plus its own
+/// leaf data.
+///
+/// # Database Mapping
+///
+/// - `Pet` table (uuid, name)
+/// - `Reptile` table (pet_id FK, scale_color, is_poisonous)
+/// - `Snake` table (reptile_id FK, length)
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (ps, _) = Snake::create(ps, "Kaa", "yellow", false, 3.5);
+///
+/// let snakes = ps.get_all_snakes();
+/// assert_eq!(snakes[0].pet.name, "Kaa");
+/// assert!(!snakes[0].reptile.is_poisonous);
+/// assert_eq!(snakes[0].snake_specific.length, 3.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snake {
+    pub pet: PetData,
+    pub reptile: ReptileData,
+    pub snake_specific: SnakeData,
+}
+
+// ============================================================================
+// Per-Species Knowledge: Associated Constants
+// ============================================================================
+
+/// Static facts about a concrete species.
+///
+/// Centralizes per-species knowledge (sound, common breeds, lifespan) in one
+/// `impl` per leaf type instead of scattering it across `match` statements.
+/// Because the facts are associated constants, they are available without
+/// an instance and cost nothing at runtime.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// assert_eq!(Dog::SOUND, "Woof!");
+/// assert!(Cat::DEFAULT_BREEDS.contains(&"siamese"));
+/// assert!(Dog::TYPICAL_LIFESPAN_YEARS.contains(&12));
+/// ```
+pub trait SpeciesInfo {
+    /// Human-readable species name, matching the leaf table name.
+    const NAME: &'static str;
+    /// Sound returned by [`Mammal::make_sound`].
+    const SOUND: &'static str;
+    /// Common breeds, used by fixtures and sample data. Empty for species
+    /// whose tables have no `breed` column.
+    const DEFAULT_BREEDS: &'static [&'static str];
+    /// Typical lifespan in years, used by [`PetState::lifecycle_stats`].
+    const TYPICAL_LIFESPAN_YEARS: RangeInclusive<u32>;
+}
+
+impl SpeciesInfo for Dog {
+    const NAME: &'static str = "Dog";
+    const SOUND: &'static str = "Woof!";
+    const DEFAULT_BREEDS: &'static [&'static str] =
+        &["labrador", "schnauzer", "beagle", "retriever", "poodle"];
+    const TYPICAL_LIFESPAN_YEARS: RangeInclusive<u32> = 10..=13;
+}
+
+impl SpeciesInfo for Cat {
+    const NAME: &'static str = "Cat";
+    const SOUND: &'static str = "Meow!";
+    const DEFAULT_BREEDS: &'static [&'static str] =
+        &["shorthair", "siamese", "persian", "tabby", "bombay"];
+    const TYPICAL_LIFESPAN_YEARS: RangeInclusive<u32> = 12..=18;
+}
+
+impl SpeciesInfo for Hamster {
+    const NAME: &'static str = "Hamster";
+    const SOUND: &'static str = "Squeak!";
+    const DEFAULT_BREEDS: &'static [&'static str] = &[
+        "syrian",
+        "roborovski",
+        "campbell",
+        "winter_white",
+        "chinese",
+    ];
+    const TYPICAL_LIFESPAN_YEARS: RangeInclusive<u32> = 2..=3;
+}
+
+impl SpeciesInfo for Turtle {
+    const NAME: &'static str = "Turtle";
+    const SOUND: &'static str = "Hiss!";
+    const DEFAULT_BREEDS: &'static [&'static str] = &[];
+    const TYPICAL_LIFESPAN_YEARS: RangeInclusive<u32> = 20..=40;
+}
+
+impl SpeciesInfo for Snake {
+    const NAME: &'static str = "Snake";
+    const SOUND: &'static str = "Hiss!";
+    const DEFAULT_BREEDS: &'static [&'static str] = &[];
+    const TYPICAL_LIFESPAN_YEARS: RangeInclusive<u32> = 15..=30;
+}
+
+/// Discriminant of a pet's leaf type, without any of its data.
+///
+/// Returned by [`PetState::ids`] where composing the full domain object
+/// would be wasted work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Species {
+    Dog,
+    Cat,
+    Hamster,
+    Turtle,
+    Snake,
+    Goldfish,
+    Betta,
+}
+
+impl Species {
+    /// The species' [`SpeciesInfo::NAME`].
+    pub fn name(self) -> &'static str {
+        match self {
+            Species::Dog => Dog::NAME,
+            Species::Cat => Cat::NAME,
+            Species::Hamster => Hamster::NAME,
+            Species::Turtle => Turtle::NAME,
+            Species::Snake => Snake::NAME,
+            Species::Goldfish => Goldfish::NAME,
+            Species::Betta => Betta::NAME,
+        }
+    }
+
+    /// The species' [`SpeciesInfo::TYPICAL_LIFESPAN_YEARS`].
+    pub fn typical_lifespan_years(self) -> RangeInclusive<u32> {
+        match self {
+            Species::Dog => Dog::TYPICAL_LIFESPAN_YEARS,
+            Species::Cat => Cat::TYPICAL_LIFESPAN_YEARS,
+            Species::Hamster => Hamster::TYPICAL_LIFESPAN_YEARS,
+            Species::Turtle => Turtle::TYPICAL_LIFESPAN_YEARS,
+            Species::Snake => Snake::TYPICAL_LIFESPAN_YEARS,
+            Species::Goldfish => Goldfish::TYPICAL_LIFESPAN_YEARS,
+            Species::Betta => Betta::TYPICAL_LIFESPAN_YEARS,
+        }
+    }
+}
+
+/// A row of the base `Pet` table, tagged with its leaf type.
+///
+/// Returned by [`PetState::get_all_pets`]. `species` is `None` for pets
+/// whose leaf table this version does not model as a [`Species`], so
+/// base-level queries still see them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PetRecord {
+    pub pet: PetData,
+    pub species: Option<Species>,
+}
+
 // ============================================================================
 // Polymorphic Type: Enum-based Solution (addresses Vec<Mammal> concern)
 // ============================================================================
@@ -255,16 +589,25 @@ pub struct Cat {
 ///
 /// - **Type safety**: `Vec<Mammal>` works without trait objects
 /// - **Zero-cost**: No heap allocation, no vtable lookup
-/// - **Exhaustiveness**: Compiler ensures all cases are handled
+/// - **Exhaustiveness**: Compiler ensures all cases are handled inside this crate
 /// - **Performance**: Pattern matching compiles to direct dispatch
 ///
+/// # Evolution
+///
+/// `Mammal` is `#[non_exhaustive]`: new leaf types (such as
+/// [`Mammal::Hamster`]) can be added without a breaking release, so matches
+/// outside this crate need a wildcard arm. Code that only needs shared
+/// columns or the species should use the accessors ([`name`](Self::name),
+/// [`mammal_data`](Self::mammal_data), [`species`](Self::species), the
+/// `as_*` downcasts) instead of matching.
+///
 /// # Database Mapping
 ///
 /// Maps naturally to Single Table Inheritance with a discriminant column:
 /// ```sql
 /// CREATE TABLE Mammals (
 ///     id UUID PRIMARY KEY,
-///     type VARCHAR,  -- 'Dog' or 'Cat' (discriminant)
+///     type VARCHAR,  -- 'Dog', 'Cat' or 'Hamster' (discriminant)
 ///     name VARCHAR,
 ///     hair_color VARCHAR,
 ///     -- Dog fields
@@ -293,13 +636,16 @@ pub struct Cat {
 ///     match mammal {
 ///         Mammal::Dog(dog) => println!("Dog: {}", dog.pet.name),
 ///         Mammal::Cat(cat) => println!("Cat: {}", cat.pet.name),
+///         other => println!("{}: {}", other.species().name(), other.name()),
 ///     }
 /// }
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum Mammal {
     Dog(Dog),
     Cat(Cat),
+    Hamster(Hamster),
 }
 
 impl Mammal {
@@ -324,6 +670,7 @@ impl Mammal {
         match self {
             Mammal::Dog(d) => &d.pet,
             Mammal::Cat(c) => &c.pet,
+            Mammal::Hamster(h) => &h.pet,
         }
     }
 
@@ -345,13 +692,14 @@ impl Mammal {
         match self {
             Mammal::Dog(d) => &d.mammal,
             Mammal::Cat(c) => &c.mammal,
+            Mammal::Hamster(h) => &h.mammal,
         }
     }
 
     /// Returns the sound this mammal makes.
     ///
     /// This demonstrates polymorphic behavior through pattern matching.
-    /// Returns a static string literal for zero-cost abstraction.
+    /// Returns the variant's [`SpeciesInfo::SOUND`] for zero-cost abstraction.
     ///
     /// # Example
     ///
@@ -368,8 +716,31 @@ impl Mammal {
     /// ```
     pub fn make_sound(&self) -> &'static str {
         match self {
-            Mammal::Dog(_) => "Woof!",
-            Mammal::Cat(_) => "Meow!",
+            Mammal::Dog(_) => Dog::SOUND,
+            Mammal::Cat(_) => Cat::SOUND,
+            Mammal::Hamster(_) => Hamster::SOUND,
+        }
+    }
+
+    /// Returns the mammal's [`Species`] discriminant.
+    ///
+    /// Lets callers branch on the leaf type without matching every variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Hamster::create(ps, "Nibbles", "golden", "syrian", true, false, true);
+    ///
+    /// assert_eq!(ps.get_all_mammals()[0].species(), Species::Hamster);
+    /// ```
+    pub fn species(&self) -> Species {
+        match self {
+            Mammal::Dog(_) => Species::Dog,
+            Mammal::Cat(_) => Species::Cat,
+            Mammal::Hamster(_) => Species::Hamster,
         }
     }
 
@@ -462,6 +833,11 @@ impl Mammal {
         matches!(self, Mammal::Cat(_))
     }
 
+    /// Returns `true` if this is a [`Mammal::Hamster`].
+    pub fn is_hamster(&self) -> bool {
+        matches!(self, Mammal::Hamster(_))
+    }
+
     /// Attempts to downcast to a [`Dog`] reference.
     ///
     /// Returns `Some(&Dog)` if this is a dog, `None` otherwise.
@@ -509,88 +885,1368 @@ impl Mammal {
             _ => None,
         }
     }
-}
-
-// ============================================================================
-// Display Implementations for User-Friendly Output
-// ============================================================================
 
-impl fmt::Display for Dog {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Dog({}, {}, breed: {}, commands: {})",
-            self.pet.name,
-            self.mammal.hair_color,
-            self.mammal.breed,
-            self.dog_specific.num_commands_known
-        )
+    /// Attempts to downcast to a [`Hamster`] reference.
+    pub fn as_hamster(&self) -> Option<&Hamster> {
+        match self {
+            Mammal::Hamster(hamster) => Some(hamster),
+            _ => None,
+        }
     }
 }
 
-impl fmt::Display for Cat {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Cat({}, {}, keyboard sitter: {})",
-            self.pet.name, self.mammal.hair_color, self.cat_specific.sits_on_keyboard
-        )
-    }
+/// Inline-allocated result list for short filtered queries.
+///
+/// Holds up to four mammals without a heap allocation and spills to the heap
+/// transparently beyond that. Requires the `compact` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "compact")] {
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (ps, _) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+///
+/// let brown: CompactMammals = ps.get_mammals_by_hair_color_compact("brown");
+/// assert_eq!(brown[0].name(), "Rex");
+/// # }
+/// ```
+#[cfg(feature = "compact")]
+pub type CompactMammals = smallvec::SmallVec<[Mammal; 4]>;
+
+/// Polymorphic enum for the reptile branch, mirroring [`Mammal`].
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (ps, _) = Turtle::create(ps, "Shelly", "olive", false, true, false);
+/// let (ps, _) = Snake::create(ps, "Nagini", "green", true, 6.0);
+///
+/// for reptile in ps.get_all_reptiles() {
+///     match reptile {
+///         Reptile::Turtle(turtle) => println!("Turtle: {}", turtle.pet.name),
+///         Reptile::Snake(snake) => println!("Snake: {}", snake.pet.name),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reptile {
+    Turtle(Turtle),
+    Snake(Snake),
 }
 
-impl fmt::Display for Mammal {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Reptile {
+    /// Returns a reference to the common [`PetData`].
+    pub fn pet_data(&self) -> &PetData {
         match self {
-            Mammal::Dog(dog) => write!(f, "{}", dog),
-            Mammal::Cat(cat) => write!(f, "{}", cat),
+            Reptile::Turtle(t) => &t.pet,
+            Reptile::Snake(s) => &s.pet,
         }
     }
-}
-
-// ============================================================================
-// Constructor Implementation
-// ============================================================================
 
-impl Dog {
-    /// Creates a new dog and adds it to the PetState
-    /// Returns the updated PetState and the UUID of the created dog
-    pub fn create(
-        mut ps: PetState,
-        name: impl Into<String>,
-        hair_color: impl Into<String>,
-        breed: impl Into<String>,
-        has_hair: bool,
-        tail_length: f64,
-        num_commands_known: i32,
-    ) -> (PetState, String) {
-        let id = ps.add_dog(
-            name.into(),
-            hair_color.into(),
-            breed.into(),
-            has_hair,
-            tail_length,
-            num_commands_known,
-        );
-        (ps, id)
+    /// Returns a reference to the common [`ReptileData`].
+    pub fn reptile_data(&self) -> &ReptileData {
+        match self {
+            Reptile::Turtle(t) => &t.reptile,
+            Reptile::Snake(s) => &s.reptile,
+        }
     }
-}
 
-impl Cat {
-    /// Creates a new cat and adds it to the PetState
-    /// Returns the updated PetState and the UUID of the created cat
-    pub fn create(
-        mut ps: PetState,
-        name: impl Into<String>,
-        hair_color: impl Into<String>,
-        breed: impl Into<String>,
-        has_hair: bool,
-        declawed: bool,
-        sits_on_keyboard: bool,
-    ) -> (PetState, String) {
-        let id = ps.add_cat(
+    /// Returns the variant's [`SpeciesInfo::SOUND`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Snake::create(ps, "Kaa", "yellow", false, 3.5);
+    ///
+    /// assert_eq!(ps.get_all_reptiles()[0].make_sound(), "Hiss!");
+    /// ```
+    pub fn make_sound(&self) -> &'static str {
+        match self {
+            Reptile::Turtle(_) => Turtle::SOUND,
+            Reptile::Snake(_) => Snake::SOUND,
+        }
+    }
+
+    /// Returns the pet's name.
+    pub fn name(&self) -> &str {
+        &self.pet_data().name
+    }
+
+    /// Returns the pet's UUID.
+    pub fn uuid(&self) -> &str {
+        &self.pet_data().uuid
+    }
+
+    /// Returns the reptile's scale color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Turtle::create(ps, "Shelly", "olive", false, true, false);
+    ///
+    /// assert_eq!(ps.get_all_reptiles()[0].scale_color(), "olive");
+    /// ```
+    pub fn scale_color(&self) -> &str {
+        &self.reptile_data().scale_color
+    }
+
+    /// Returns `true` if the reptile is poisonous.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Snake::create(ps, "Nagini", "green", true, 6.0);
+    ///
+    /// assert!(ps.get_all_reptiles()[0].is_poisonous());
+    /// ```
+    pub fn is_poisonous(&self) -> bool {
+        self.reptile_data().is_poisonous
+    }
+
+    /// Returns `true` if this reptile is a turtle.
+    pub fn is_turtle(&self) -> bool {
+        matches!(self, Reptile::Turtle(_))
+    }
+
+    /// Returns `true` if this reptile is a snake.
+    pub fn is_snake(&self) -> bool {
+        matches!(self, Reptile::Snake(_))
+    }
+
+    /// Returns the turtle if this reptile is one.
+    pub fn as_turtle(&self) -> Option<&Turtle> {
+        match self {
+            Reptile::Turtle(turtle) => Some(turtle),
+            _ => None,
+        }
+    }
+
+    /// Returns the snake if this reptile is one.
+    pub fn as_snake(&self) -> Option<&Snake> {
+        match self {
+            Reptile::Snake(snake) => Some(snake),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Partial Updates: Patch Structs
+// ============================================================================
+
+/// Subset of a dog's columns to overwrite, applied by [`PetState::apply_patch`].
+///
+/// Every field is optional; `None` leaves the column unchanged. `breed` is
+/// doubly optional because the column is nullable: `Some(None)` sets it to
+/// `NULL`.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+///
+/// let patch = DogPatch {
+///     num_commands_known: Some(20),
+///     breed: Some(None),
+///     ..Default::default()
+/// };
+/// ps.apply_patch(&rex, patch).unwrap();
+///
+/// let dog = &ps.get_all_dogs()[0];
+/// assert_eq!(dog.dog_specific.num_commands_known, 20);
+/// assert_eq!(dog.mammal.breed, None);
+/// assert_eq!(dog.pet.name, "Rex");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DogPatch {
+    pub name: Option<String>,
+    pub hair_color: Option<String>,
+    pub breed: Option<Option<String>>,
+    pub has_hair: Option<bool>,
+    pub tail_length: Option<f64>,
+    pub num_commands_known: Option<i32>,
+}
+
+/// Subset of a cat's columns to overwrite, applied by [`PetState::apply_patch`].
+///
+/// Field semantics match [`DogPatch`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CatPatch {
+    pub name: Option<String>,
+    pub hair_color: Option<String>,
+    pub breed: Option<Option<String>>,
+    pub has_hair: Option<bool>,
+    pub declawed: Option<bool>,
+    pub sits_on_keyboard: Option<bool>,
+}
+
+/// A patch for any species, accepted by [`PetState::apply_patch`].
+///
+/// Usually built implicitly from a [`DogPatch`] or [`CatPatch`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PetPatch {
+    Dog(DogPatch),
+    Cat(CatPatch),
+}
+
+impl From<DogPatch> for PetPatch {
+    fn from(patch: DogPatch) -> Self {
+        PetPatch::Dog(patch)
+    }
+}
+
+impl From<CatPatch> for PetPatch {
+    fn from(patch: CatPatch) -> Self {
+        PetPatch::Cat(patch)
+    }
+}
+
+impl DogPatch {
+    /// Overwrites the columns this patch sets.
+    fn apply(self, dog: &mut DogData, mammal: &mut MammalData, pet: &mut PetData) {
+        apply_common(
+            (self.name, self.hair_color, self.breed, self.has_hair),
+            mammal,
+            pet,
+        );
+        if let Some(tail_length) = self.tail_length {
+            dog.tail_length = tail_length;
+        }
+        if let Some(num_commands_known) = self.num_commands_known {
+            dog.num_commands_known = num_commands_known;
+        }
+    }
+}
+
+impl CatPatch {
+    /// Overwrites the columns this patch sets.
+    fn apply(self, cat: &mut CatData, mammal: &mut MammalData, pet: &mut PetData) {
+        apply_common(
+            (self.name, self.hair_color, self.breed, self.has_hair),
+            mammal,
+            pet,
+        );
+        if let Some(declawed) = self.declawed {
+            cat.declawed = declawed;
+        }
+        if let Some(sits_on_keyboard) = self.sits_on_keyboard {
+            cat.sits_on_keyboard = sits_on_keyboard;
+        }
+    }
+}
+
+/// Patch fields shared by every mammal: name, hair color, breed, has_hair.
+type CommonPatch = (
+    Option<String>,
+    Option<String>,
+    Option<Option<String>>,
+    Option<bool>,
+);
+
+/// Applies the Pet and Mammal columns of a patch.
+fn apply_common(
+    (name, hair_color, breed, has_hair): CommonPatch,
+    mammal: &mut MammalData,
+    pet: &mut PetData,
+) {
+    if let Some(name) = name {
+        pet.name = name;
+    }
+    if let Some(hair_color) = hair_color {
+        mammal.hair_color = hair_color;
+    }
+    if let Some(breed) = breed {
+        mammal.breed = breed;
+    }
+    if let Some(has_hair) = has_hair {
+        mammal.has_hair = has_hair;
+    }
+}
+
+// ============================================================================
+// Reclassification: Changing the Leaf Type
+// ============================================================================
+
+/// Leaf-table row for a species, used by [`PetState::reclassify`].
+///
+/// Usually built implicitly from a [`DogData`] or [`CatData`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpeciesData {
+    Dog(DogData),
+    Cat(CatData),
+    Hamster(HamsterData),
+}
+
+impl From<DogData> for SpeciesData {
+    fn from(data: DogData) -> Self {
+        SpeciesData::Dog(data)
+    }
+}
+
+impl From<CatData> for SpeciesData {
+    fn from(data: CatData) -> Self {
+        SpeciesData::Cat(data)
+    }
+}
+
+impl From<HamsterData> for SpeciesData {
+    fn from(data: HamsterData) -> Self {
+        SpeciesData::Hamster(data)
+    }
+}
+
+// ============================================================================
+// Bulk Insert: Input Rows
+// ============================================================================
+
+/// Column values for one dog inserted by [`PetState::add_dogs`].
+///
+/// Holds the same values as the [`Dog::create`] arguments; the UUID is
+/// generated on insert.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DogInput {
+    pub name: String,
+    pub hair_color: String,
+    pub breed: Option<String>,
+    pub has_hair: bool,
+    pub tail_length: f64,
+    pub num_commands_known: i32,
+}
+
+/// Column values for one cat inserted by [`PetState::add_cats`].
+///
+/// Holds the same values as the [`Cat::create`] arguments; the UUID is
+/// generated on insert.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatInput {
+    pub name: String,
+    pub hair_color: String,
+    pub breed: Option<String>,
+    pub has_hair: bool,
+    pub declawed: bool,
+    pub sits_on_keyboard: bool,
+}
+
+// ============================================================================
+// Aggregation: One-Pass Statistics over the Join
+// ============================================================================
+
+/// Row filter applied by [`PetState::aggregate`].
+///
+/// Matches against the `Mammal` table, like a SQL `WHERE` clause on the
+/// shared columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MammalFilter {
+    /// Every mammal.
+    All,
+    /// Mammals with exactly this hair color.
+    HairColor(String),
+    /// Mammals with exactly this breed.
+    Breed(String),
+}
+
+/// Numeric column that [`Aggregator::Sum`] and [`Aggregator::Avg`] operate on.
+///
+/// Both columns live in the `Dog` table, so cats contribute `NULL` for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericField {
+    /// `Dog.tail_length`
+    TailLength,
+    /// `Dog.num_commands_known`
+    NumCommandsKnown,
+}
+
+/// Aggregate function evaluated by [`PetState::aggregate`].
+///
+/// Mirrors SQL semantics: `Count` is `COUNT(*)`, while `Sum` and `Avg`
+/// ignore rows where the column is `NULL` (e.g. cats for dog-only fields).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregator {
+    Count,
+    Sum(NumericField),
+    Avg(NumericField),
+}
+
+/// Headline counts over the whole state, returned by [`PetState::summary`].
+///
+/// Species and distinct-value counts cover visible pets only, like the
+/// `get_all_*` queries. `total_entities` counts every stored pet, hidden or
+/// not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PetStateStats {
+    /// Visible dogs, working dogs included.
+    pub dogs: usize,
+    /// Visible cats.
+    pub cats: usize,
+    /// Visible hamsters.
+    pub hamsters: usize,
+    /// Visible turtles.
+    pub turtles: usize,
+    /// Visible snakes.
+    pub snakes: usize,
+    /// Visible fish, goldfish and bettas together.
+    pub fish: usize,
+    /// Distinct non-`NULL` breeds.
+    pub distinct_breeds: usize,
+    /// Distinct hair colors among visible mammals.
+    pub distinct_hair_colors: usize,
+    /// Every stored pet, including deceased and soft-deleted ones.
+    pub total_entities: usize,
+    /// Waitlist entries summed over every shelter; see
+    /// [`PetState::occupancy`] for the per-shelter queues.
+    pub waiting: usize,
+}
+
+impl PetStateStats {
+    /// Visible mammals of every leaf type.
+    pub fn mammals(&self) -> usize {
+        self.dogs + self.cats + self.hamsters
+    }
+
+    /// Visible reptiles of every leaf type.
+    pub fn reptiles(&self) -> usize {
+        self.turtles + self.snakes
+    }
+}
+
+// ============================================================================
+// Pagination
+// ============================================================================
+
+/// One page of results from [`PetState::get_mammals_after`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MammalPage {
+    pub items: Vec<Mammal>,
+    /// Cursor for the following page, or `None` if this is the last one.
+    pub next_cursor: Option<String>,
+}
+
+// ============================================================================
+// Sorting
+// ============================================================================
+
+/// Column that [`PetState::get_all_mammals_sorted`] orders by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    /// `Pet.name`
+    Name,
+    /// `Mammal.hair_color`
+    HairColor,
+    /// `Pet.id`
+    Uuid,
+}
+
+/// Sort direction, like SQL `ASC` / `DESC`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+// ============================================================================
+// Lifecycle: Domain State Over Time
+// ============================================================================
+
+/// A calendar date, ordered chronologically.
+///
+/// Deliberately minimal (no time zones, no arithmetic): it only needs to be
+/// stored, compared, and printed, like a SQL `DATE` column.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::Date;
+///
+/// let earlier = Date::new(2023, 12, 31);
+/// let later = Date::new(2024, 1, 1);
+/// assert!(earlier < later);
+/// assert_eq!(later.to_string(), "2024-01-01");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Creates a date from its year, month (1-12), and day (1-31).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is outside 1-12 or `day` is outside the month's
+    /// range, leap years included.
+    ///
+    /// ```should_panic
+    /// use ecs_inheritance_patterns::pet_module::Date;
+    ///
+    /// Date::new(2023, 2, 29);
+    /// ```
+    pub const fn new(year: i32, month: u8, day: u8) -> Self {
+        assert!(month >= 1 && month <= 12, "month must be 1-12");
+        assert!(
+            day >= 1 && day <= days_in_month(year, month),
+            "day out of range for month"
+        );
+        Self { year, month, day }
+    }
+}
+
+/// Number of days in `month` of `year` (proleptic Gregorian calendar).
+const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Whether a pet is alive or deceased.
+///
+/// This is domain state, recorded with [`PetState::mark_deceased`]. It is
+/// distinct from deleting a record: a deceased pet's data is kept, but
+/// collection queries skip it unless [`PetState::include_deceased`] is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LifecycleStatus {
+    Alive,
+    Deceased,
+}
+
+/// Population counts by [`LifecycleStatus`].
+///
+/// Returned by [`PetState::lifecycle_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LifecycleStats {
+    pub alive: usize,
+    pub deceased: usize,
+    /// Earliest recorded date of death, if any pet is deceased.
+    pub first_death: Option<Date>,
+    /// Latest recorded date of death, if any pet is deceased.
+    pub last_death: Option<Date>,
+    /// Shortest typical lifespan among the living pets' species, per
+    /// [`SpeciesInfo::TYPICAL_LIFESPAN_YEARS`].
+    pub min_typical_lifespan_years: Option<u32>,
+    /// Longest typical lifespan among the living pets' species: how far
+    /// ahead care for the current population needs to be planned.
+    pub max_typical_lifespan_years: Option<u32>,
+}
+
+// ============================================================================
+// Associations: Insurance Policies
+// ============================================================================
+
+/// An insurance policy covering a single pet.
+///
+/// Policies are rows in their own `Policy` table with a foreign key to
+/// `Pet`, not part of the pet hierarchy. Attach one with
+/// [`PetState::attach_policy`].
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let policy = InsurancePolicy {
+///     provider: "PetCare".to_string(),
+///     policy_number: "PC-001".to_string(),
+///     premium: 29.90,
+///     coverage_start: Date::new(2024, 1, 1),
+///     coverage_end: Date::new(2024, 12, 31),
+/// };
+///
+/// assert!(policy.covers(Date::new(2024, 6, 15)));
+/// assert!(!policy.covers(Date::new(2025, 1, 1)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsurancePolicy {
+    pub provider: String,
+    pub policy_number: String,
+    /// Monthly premium.
+    pub premium: f64,
+    /// First covered day (inclusive).
+    pub coverage_start: Date,
+    /// Last covered day (inclusive).
+    pub coverage_end: Date,
+}
+
+impl InsurancePolicy {
+    /// Returns `true` if `date` falls within the coverage period.
+    pub fn covers(&self, date: Date) -> bool {
+        (self.coverage_start..=self.coverage_end).contains(&date)
+    }
+}
+
+// ============================================================================
+// Associations: Shelters
+// ============================================================================
+
+/// A shelter that can house a limited number of pets.
+///
+/// Shelters are rows in their own `Shelter` table; a housed pet carries a
+/// foreign key to its shelter. Create one with [`PetState::add_shelter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shelter {
+    pub uuid: String,
+    pub name: String,
+    /// Maximum number of pets housed at once.
+    pub capacity: usize,
+}
+
+/// Current residents and waitlist of a shelter, returned by [`PetState::occupancy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occupancy {
+    pub capacity: usize,
+    pub residents: Vec<PetData>,
+    /// Pets waiting for a place, in admission order.
+    pub waitlist: Vec<PetData>,
+}
+
+impl Occupancy {
+    /// Returns `true` when no more pets can be housed.
+    pub fn is_full(&self) -> bool {
+        self.residents.len() >= self.capacity
+    }
+}
+
+/// What happens to linked pets when a shelter is deleted.
+///
+/// Mirrors SQL foreign key `ON DELETE` actions for `Pet.shelter_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CascadePolicy {
+    /// `ON DELETE CASCADE`: delete the housed pets too.
+    Cascade,
+    /// `ON DELETE SET NULL`: keep the pets but unhouse them.
+    Nullify,
+    /// `ON DELETE RESTRICT`: refuse while any pet is housed there.
+    Restrict,
+}
+
+/// Outcome of a bulk relationship update such as [`PetState::evacuate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TransferSummary {
+    /// Pets whose shelter changed.
+    pub moved: usize,
+    /// Waiting pets moved to the destination's waitlist.
+    pub requeued: usize,
+}
+
+// ============================================================================
+// Fish Hierarchy and Tanks
+// ============================================================================
+
+/// Water a fish lives in, and that a tank holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaterType {
+    Fresh,
+    Salt,
+}
+
+/// Data shared by all fish.
+///
+/// Maps to the `Fish` table in Class Table Inheritance pattern, the third
+/// branch under `Pet` next to `Mammal` and `Reptile`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FishData {
+    pub water_type: WaterType,
+    pub fin_count: u32,
+}
+
+/// Goldfish-specific data.
+///
+/// Maps to the `Goldfish` table, with a foreign key to the `Fish` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoldfishData {
+    pub is_fancy: bool,
+}
+
+/// Betta-specific data.
+///
+/// Maps to the `Betta` table, with a foreign key to the `Fish` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BettaData {
+    pub is_male: bool,
+}
+
+/// A goldfish entity.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (ps, _) = Goldfish::create(ps, "Bubbles", WaterType::Fresh, 7, true);
+///
+/// let goldfish = ps.get_all_goldfish();
+/// assert_eq!(goldfish[0].pet.name, "Bubbles");
+/// assert!(goldfish[0].goldfish_specific.is_fancy);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Goldfish {
+    pub pet: PetData,
+    pub fish: FishData,
+    pub goldfish_specific: GoldfishData,
+}
+
+/// A betta entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Betta {
+    pub pet: PetData,
+    pub fish: FishData,
+    pub betta_specific: BettaData,
+}
+
+impl SpeciesInfo for Goldfish {
+    const NAME: &'static str = "Goldfish";
+    const SOUND: &'static str = "Blub!";
+    const DEFAULT_BREEDS: &'static [&'static str] = &[];
+    const TYPICAL_LIFESPAN_YEARS: RangeInclusive<u32> = 10..=15;
+}
+
+impl SpeciesInfo for Betta {
+    const NAME: &'static str = "Betta";
+    const SOUND: &'static str = "Blub!";
+    const DEFAULT_BREEDS: &'static [&'static str] = &[];
+    const TYPICAL_LIFESPAN_YEARS: RangeInclusive<u32> = 3..=5;
+}
+
+/// Polymorphic enum for the fish branch, mirroring [`Mammal`] and [`Reptile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fish {
+    Goldfish(Goldfish),
+    Betta(Betta),
+}
+
+impl Fish {
+    /// Returns a reference to the common [`PetData`].
+    pub fn pet_data(&self) -> &PetData {
+        match self {
+            Fish::Goldfish(g) => &g.pet,
+            Fish::Betta(b) => &b.pet,
+        }
+    }
+
+    /// Returns a reference to the common [`FishData`].
+    pub fn fish_data(&self) -> &FishData {
+        match self {
+            Fish::Goldfish(g) => &g.fish,
+            Fish::Betta(b) => &b.fish,
+        }
+    }
+
+    /// Returns the pet's name.
+    pub fn name(&self) -> &str {
+        &self.pet_data().name
+    }
+
+    /// Returns the pet's UUID.
+    pub fn uuid(&self) -> &str {
+        &self.pet_data().uuid
+    }
+}
+
+/// An aquarium that fish can be placed in.
+///
+/// Tanks are rows in their own `Tank` table; a fish in a tank carries a
+/// foreign key to it. Create one with [`PetState::add_tank`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tank {
+    pub uuid: String,
+    pub name: String,
+    pub water_type: WaterType,
+}
+
+// ============================================================================
+// Working Dogs (Four-Level Hierarchy)
+// ============================================================================
+
+/// Data shared by all working dogs.
+///
+/// Maps to the `WorkingDog` table, with a foreign key to the `Dog` table.
+/// This is the fourth level: Pet → Mammal → Dog → WorkingDog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkingDogData {
+    pub years_of_service: u32,
+}
+
+/// Service-dog-specific data.
+///
+/// Maps to the `ServiceDog` table, with a foreign key to the `WorkingDog` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceDogData {
+    /// What the dog assists its handler with, e.g. `"guide"` or `"hearing"`.
+    pub assists_with: String,
+}
+
+/// Herding-dog-specific data.
+///
+/// Maps to the `HerdingDog` table, with a foreign key to the `WorkingDog` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HerdingDogData {
+    /// The livestock the dog herds, e.g. `"sheep"`.
+    pub livestock: String,
+}
+
+/// A service dog entity, five tables deep.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (ps, _) = ServiceDog::create(
+///     ps,
+///     "Buddy",
+///     MammalData { hair_color: "yellow".into(), breed: Some("labrador".into()), has_hair: true },
+///     DogData { tail_length: 11.0, num_commands_known: 40 },
+///     WorkingDogData { years_of_service: 3 },
+///     ServiceDogData { assists_with: "guide".into() },
+/// );
+///
+/// let buddy = &ps.get_all_service_dogs()[0];
+/// assert_eq!(buddy.service_specific.assists_with, "guide");
+///
+/// // A working dog is still a dog
+/// assert_eq!(ps.get_all_dogs()[0].pet.name, "Buddy");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceDog {
+    pub pet: PetData,
+    pub mammal: MammalData,
+    pub dog: DogData,
+    pub working_dog: WorkingDogData,
+    pub service_specific: ServiceDogData,
+}
+
+/// A herding dog entity, five tables deep.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HerdingDog {
+    pub pet: PetData,
+    pub mammal: MammalData,
+    pub dog: DogData,
+    pub working_dog: WorkingDogData,
+    pub herding_specific: HerdingDogData,
+}
+
+/// Polymorphic enum for working dogs, nested one level below [`Mammal::Dog`].
+///
+/// Converting into [`Dog`] drops the working-dog levels, the equivalent of
+/// upcasting to the base class.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let mut ps = PetState::new();
+/// let id = ps.create_herding_dog(
+///     "Shep",
+///     MammalData { hair_color: "black".into(), breed: Some("collie".into()), has_hair: true },
+///     DogData { tail_length: 14.0, num_commands_known: 25 },
+///     WorkingDogData { years_of_service: 5 },
+///     HerdingDogData { livestock: "sheep".into() },
+/// );
+///
+/// let working = ps.get_working_dog_by_id(&id).unwrap();
+/// assert!(matches!(working, WorkingDog::Herding(_)));
+///
+/// let dog = Dog::from(working);
+/// assert_eq!(Some(dog), ps.get_dog_by_id(&id));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkingDog {
+    Service(ServiceDog),
+    Herding(HerdingDog),
+}
+
+impl WorkingDog {
+    /// Returns a reference to the common [`PetData`].
+    pub fn pet_data(&self) -> &PetData {
+        match self {
+            WorkingDog::Service(d) => &d.pet,
+            WorkingDog::Herding(d) => &d.pet,
+        }
+    }
+
+    /// Returns a reference to the common [`WorkingDogData`].
+    pub fn working_dog_data(&self) -> &WorkingDogData {
+        match self {
+            WorkingDog::Service(d) => &d.working_dog,
+            WorkingDog::Herding(d) => &d.working_dog,
+        }
+    }
+
+    /// Returns the pet's name.
+    pub fn name(&self) -> &str {
+        &self.pet_data().name
+    }
+
+    /// Returns the pet's UUID.
+    pub fn uuid(&self) -> &str {
+        &self.pet_data().uuid
+    }
+}
+
+impl From<WorkingDog> for Dog {
+    fn from(working: WorkingDog) -> Self {
+        match working {
+            WorkingDog::Service(d) => Dog {
+                pet: d.pet,
+                mammal: d.mammal,
+                dog_specific: d.dog,
+            },
+            WorkingDog::Herding(d) => Dog {
+                pet: d.pet,
+                mammal: d.mammal,
+                dog_specific: d.dog,
+            },
+        }
+    }
+}
+
+// ============================================================================
+// Markers
+// ============================================================================
+
+/// Optional flags that can be attached to any pet, whatever its species.
+///
+/// In a strict single-inheritance model each flag would need a column on
+/// the base class or a copy on every subclass; in ECS each is a separate
+/// zero-sized component that any entity may or may not carry.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+///
+/// ps.attach_marker(&rex, Marker::Trained).unwrap();
+/// assert!(ps.has_marker(&rex, Marker::Trained));
+/// assert!(!ps.has_marker(&rex, Marker::Adopted));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Marker {
+    Trained,
+    Vaccinated,
+    Adopted,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors returned by fallible [`PetState`] operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PetError {
+    /// No pet has the given UUID.
+    PetNotFound(String),
+    /// A pet with the given UUID already exists.
+    DuplicateId(String),
+    /// A pet with the given name already exists and
+    /// [`PetStateOptions::unique_names`] is enabled.
+    DuplicateName(String),
+    /// No shelter has the given UUID.
+    ShelterNotFound(String),
+    /// No tank has the given UUID.
+    TankNotFound(String),
+    /// The fish lives in a different water type than the tank holds.
+    WaterTypeMismatch { fish: String, tank: String },
+    /// The shelter already houses as many pets as its capacity allows.
+    CapacityExceeded { shelter: String, capacity: usize },
+    /// [`CascadePolicy::Restrict`] refused to delete a shelter with residents.
+    DeleteRestricted { shelter: String, residents: usize },
+    /// A configuration value could not be parsed.
+    InvalidConfig { key: String, value: String },
+    /// A name or breed is longer than the configured [`PetStateOptions`] allow.
+    InputTooLong {
+        field: &'static str,
+        len: usize,
+        max: usize,
+    },
+    /// An extension component was used before
+    /// [`PetState::register_extension_component`] was called for its type.
+    ExtensionNotRegistered(&'static str),
+}
+
+impl fmt::Display for PetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PetError::PetNotFound(uuid) => write!(f, "no pet with id {uuid}"),
+            PetError::DuplicateId(uuid) => write!(f, "a pet with id {uuid} already exists"),
+            PetError::DuplicateName(name) => write!(f, "a pet named {name:?} already exists"),
+            PetError::ShelterNotFound(uuid) => write!(f, "no shelter with id {uuid}"),
+            PetError::TankNotFound(uuid) => write!(f, "no tank with id {uuid}"),
+            PetError::WaterTypeMismatch { fish, tank } => {
+                write!(
+                    f,
+                    "fish {fish} cannot live in tank {tank}: wrong water type"
+                )
+            }
+            PetError::CapacityExceeded { shelter, capacity } => {
+                write!(f, "shelter {shelter} is full (capacity {capacity})")
+            }
+            PetError::DeleteRestricted { shelter, residents } => {
+                write!(f, "shelter {shelter} still houses {residents} pet(s)")
+            }
+            PetError::InvalidConfig { key, value } => {
+                write!(f, "invalid value {value:?} for {key}")
+            }
+            PetError::InputTooLong { field, len, max } => {
+                write!(f, "{field} is {len} characters long (max {max})")
+            }
+            PetError::ExtensionNotRegistered(type_name) => {
+                write!(f, "extension component {type_name} is not registered")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PetError {}
+
+// ============================================================================
+// Storage Diagnostics
+// ============================================================================
+
+/// Snapshot of how densely the ECS storages are packed.
+///
+/// Deleting pets leaves vacant entity slots behind. Component storages keep
+/// their memory for those slots until new pets reuse them, so heavy deletion
+/// churn shows up here as fragmentation.
+///
+/// Returned by [`PetState::storage_report`].
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let report = StorageReport { live_entities: 2, vacant_slots: 6 };
+/// assert_eq!(report.fragmentation(), 0.75);
+/// assert!(report.is_fragmented());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageReport {
+    /// Entities currently alive (pets and associated rows).
+    pub live_entities: usize,
+    /// Deleted slots below the highest live entity, waiting to be reused.
+    pub vacant_slots: usize,
+}
+
+impl StorageReport {
+    /// Fragmentation above which [`is_fragmented`](Self::is_fragmented) reports `true`.
+    pub const FRAGMENTATION_THRESHOLD: f32 = 0.5;
+
+    /// Fraction of occupied-range slots that are vacant, from `0.0` to `1.0`.
+    pub fn fragmentation(&self) -> f32 {
+        let slots = self.live_entities + self.vacant_slots;
+        if slots == 0 {
+            0.0
+        } else {
+            self.vacant_slots as f32 / slots as f32
+        }
+    }
+
+    /// Returns `true` when fragmentation exceeds [`Self::FRAGMENTATION_THRESHOLD`].
+    pub fn is_fragmented(&self) -> bool {
+        self.fragmentation() > Self::FRAGMENTATION_THRESHOLD
+    }
+}
+
+/// Every component attached to one pet entity, returned by [`PetState::describe_entity`].
+///
+/// The reverse of a query: instead of asking which entities have a given
+/// set of components, it lists the tables a single entity has rows in.
+/// Values are rendered with their `Debug` representation, so new optional
+/// components show up without a dedicated accessor.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let ps = PetState::new();
+/// let (ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+///
+/// let description = ps.describe_entity(&rex).unwrap();
+/// assert!(description.has("Dog"));
+/// assert!(!description.has("Cat"));
+/// assert!(description.to_string().contains("Rex"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityDescription {
+    pub uuid: String,
+    /// Components present on the entity, in hierarchy order.
+    pub components: Vec<ComponentDescription>,
+}
+
+impl EntityDescription {
+    /// Returns `true` if the entity has a row in `table`.
+    pub fn has(&self, table: &str) -> bool {
+        self.components.iter().any(|c| c.table == table)
+    }
+}
+
+/// One component of an [`EntityDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentDescription {
+    /// Table the component stands for (`"Pet"`, `"Mammal"`, `"Dog"`, ...).
+    pub table: &'static str,
+    /// `Debug` rendering of the component's current value.
+    pub value: String,
+}
+
+// ============================================================================
+// Display Implementations for User-Friendly Output
+// ============================================================================
+
+impl fmt::Display for Dog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Dog({}, {}, breed: {}, commands: {})",
+            self.pet.name,
+            self.mammal.hair_color,
+            self.mammal.breed.as_deref().unwrap_or("unknown"),
+            self.dog_specific.num_commands_known
+        )
+    }
+}
+
+impl fmt::Display for Cat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Cat({}, {}, keyboard sitter: {})",
+            self.pet.name, self.mammal.hair_color, self.cat_specific.sits_on_keyboard
+        )
+    }
+}
+
+impl fmt::Display for Hamster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Hamster({}, {}, dwarf: {})",
+            self.pet.name, self.mammal.hair_color, self.hamster_specific.is_dwarf
+        )
+    }
+}
+
+impl fmt::Display for Turtle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Turtle({}, {}, aquatic: {})",
+            self.pet.name, self.reptile.scale_color, self.turtle_specific.is_aquatic
+        )
+    }
+}
+
+impl fmt::Display for Snake {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Snake({}, {}, length: {})",
+            self.pet.name, self.reptile.scale_color, self.snake_specific.length
+        )
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for StorageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} live, {} vacant ({:.0}% fragmented)",
+            self.live_entities,
+            self.vacant_slots,
+            self.fragmentation() * 100.0
+        )?;
+        if self.is_fragmented() {
+            write!(
+                f,
+                " - consider rebuilding the state, or DenseVecStorage for sparse components"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for EntityDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Entity {}", self.uuid)?;
+        for component in &self.components {
+            write!(f, "\n  {}: {}", component.table, component.value)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Mammal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mammal::Dog(dog) => write!(f, "{}", dog),
+            Mammal::Cat(cat) => write!(f, "{}", cat),
+            Mammal::Hamster(hamster) => write!(f, "{}", hamster),
+        }
+    }
+}
+
+impl fmt::Display for Reptile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reptile::Turtle(turtle) => write!(f, "{}", turtle),
+            Reptile::Snake(snake) => write!(f, "{}", snake),
+        }
+    }
+}
+
+impl fmt::Display for Goldfish {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Goldfish({}, fins: {}, fancy: {})",
+            self.pet.name, self.fish.fin_count, self.goldfish_specific.is_fancy
+        )
+    }
+}
+
+impl fmt::Display for Betta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Betta({}, fins: {}, male: {})",
+            self.pet.name, self.fish.fin_count, self.betta_specific.is_male
+        )
+    }
+}
+
+impl fmt::Display for Fish {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fish::Goldfish(goldfish) => write!(f, "{}", goldfish),
+            Fish::Betta(betta) => write!(f, "{}", betta),
+        }
+    }
+}
+
+impl fmt::Display for ServiceDog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ServiceDog({}, {}, assists with: {})",
+            self.pet.name, self.mammal.hair_color, self.service_specific.assists_with
+        )
+    }
+}
+
+impl fmt::Display for HerdingDog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HerdingDog({}, {}, herds: {})",
+            self.pet.name, self.mammal.hair_color, self.herding_specific.livestock
+        )
+    }
+}
+
+impl fmt::Display for WorkingDog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkingDog::Service(dog) => write!(f, "{}", dog),
+            WorkingDog::Herding(dog) => write!(f, "{}", dog),
+        }
+    }
+}
+
+// ============================================================================
+// Constructor Implementation
+// ============================================================================
+
+impl Dog {
+    /// Creates a new dog and adds it to the PetState
+    /// Returns the updated PetState and the UUID of the created dog
+    ///
+    /// Consuming form of [`PetState::create_dog`], which is easier to use
+    /// inside loops and structs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the state's [`PetStateOptions`], or if its
+    /// [`IdGenerator`] returns an id already in use.
+    pub fn create(
+        mut ps: PetState,
+        name: impl Into<String>,
+        hair_color: impl Into<String>,
+        breed: impl IntoNullable<String>,
+        has_hair: bool,
+        tail_length: f64,
+        num_commands_known: i32,
+    ) -> (PetState, String) {
+        let id = ps.create_dog(
+            name.into(),
+            hair_color.into(),
+            breed,
+            has_hair,
+            tail_length,
+            num_commands_known,
+        );
+        (ps, id)
+    }
+}
+
+impl Cat {
+    /// Creates a new cat and adds it to the PetState
+    /// Returns the updated PetState and the UUID of the created cat
+    ///
+    /// Consuming form of [`PetState::create_cat`], which is easier to use
+    /// inside loops and structs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the state's [`PetStateOptions`], or if its
+    /// [`IdGenerator`] returns an id already in use.
+    pub fn create(
+        mut ps: PetState,
+        name: impl Into<String>,
+        hair_color: impl Into<String>,
+        breed: impl IntoNullable<String>,
+        has_hair: bool,
+        declawed: bool,
+        sits_on_keyboard: bool,
+    ) -> (PetState, String) {
+        let id = ps.create_cat(
             name.into(),
             hair_color.into(),
-            breed.into(),
+            breed,
             has_hair,
             declawed,
             sits_on_keyboard,
@@ -598,3 +2254,175 @@ impl Cat {
         (ps, id)
     }
 }
+
+impl Hamster {
+    /// Creates a new hamster and adds it to the PetState
+    /// Returns the updated PetState and the UUID of the created hamster
+    ///
+    /// Consuming form of [`PetState::create_hamster`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the state's [`PetStateOptions`], or if its
+    /// [`IdGenerator`] returns an id already in use.
+    pub fn create(
+        mut ps: PetState,
+        name: impl Into<String>,
+        hair_color: impl Into<String>,
+        breed: impl IntoNullable<String>,
+        has_hair: bool,
+        is_dwarf: bool,
+        hoards_food: bool,
+    ) -> (PetState, String) {
+        let id = ps.create_hamster(
+            name.into(),
+            hair_color.into(),
+            breed,
+            has_hair,
+            is_dwarf,
+            hoards_food,
+        );
+        (ps, id)
+    }
+}
+
+impl Turtle {
+    /// Creates a new turtle and adds it to the PetState
+    /// Returns the updated PetState and the UUID of the created turtle
+    ///
+    /// Consuming form of [`PetState::create_turtle`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name exceeds the state's [`PetStateOptions`], or if its
+    /// [`IdGenerator`] returns an id already in use.
+    pub fn create(
+        mut ps: PetState,
+        name: impl Into<String>,
+        scale_color: impl Into<String>,
+        is_poisonous: bool,
+        is_aquatic: bool,
+        is_soft_shelled: bool,
+    ) -> (PetState, String) {
+        let id = ps.create_turtle(
+            name.into(),
+            scale_color.into(),
+            is_poisonous,
+            is_aquatic,
+            is_soft_shelled,
+        );
+        (ps, id)
+    }
+}
+
+impl Snake {
+    /// Creates a new snake and adds it to the PetState
+    /// Returns the updated PetState and the UUID of the created snake
+    ///
+    /// Consuming form of [`PetState::create_snake`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name exceeds the state's [`PetStateOptions`], or if its
+    /// [`IdGenerator`] returns an id already in use.
+    pub fn create(
+        mut ps: PetState,
+        name: impl Into<String>,
+        scale_color: impl Into<String>,
+        is_poisonous: bool,
+        length: f64,
+    ) -> (PetState, String) {
+        let id = ps.create_snake(name.into(), scale_color.into(), is_poisonous, length);
+        (ps, id)
+    }
+}
+
+impl Goldfish {
+    /// Creates a new goldfish and adds it to the PetState
+    /// Returns the updated PetState and the UUID of the created goldfish
+    ///
+    /// Consuming form of [`PetState::create_goldfish`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name exceeds the state's [`PetStateOptions`], or if its
+    /// [`IdGenerator`] returns an id already in use.
+    pub fn create(
+        mut ps: PetState,
+        name: impl Into<String>,
+        water_type: WaterType,
+        fin_count: u32,
+        is_fancy: bool,
+    ) -> (PetState, String) {
+        let id = ps.create_goldfish(name.into(), water_type, fin_count, is_fancy);
+        (ps, id)
+    }
+}
+
+impl Betta {
+    /// Creates a new betta and adds it to the PetState
+    /// Returns the updated PetState and the UUID of the created betta
+    ///
+    /// Consuming form of [`PetState::create_betta`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name exceeds the state's [`PetStateOptions`], or if its
+    /// [`IdGenerator`] returns an id already in use.
+    pub fn create(
+        mut ps: PetState,
+        name: impl Into<String>,
+        water_type: WaterType,
+        fin_count: u32,
+        is_male: bool,
+    ) -> (PetState, String) {
+        let id = ps.create_betta(name.into(), water_type, fin_count, is_male);
+        (ps, id)
+    }
+}
+
+impl ServiceDog {
+    /// Creates a new service dog and adds it to the PetState
+    /// Returns the updated PetState and the UUID of the created dog
+    ///
+    /// Consuming form of [`PetState::create_service_dog`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the state's [`PetStateOptions`], or if its
+    /// [`IdGenerator`] returns an id already in use.
+    pub fn create(
+        mut ps: PetState,
+        name: impl Into<String>,
+        mammal: MammalData,
+        dog: DogData,
+        working_dog: WorkingDogData,
+        service: ServiceDogData,
+    ) -> (PetState, String) {
+        let id = ps.create_service_dog(name, mammal, dog, working_dog, service);
+        (ps, id)
+    }
+}
+
+impl HerdingDog {
+    /// Creates a new herding dog and adds it to the PetState
+    /// Returns the updated PetState and the UUID of the created dog
+    ///
+    /// Consuming form of [`PetState::create_herding_dog`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the state's [`PetStateOptions`], or if its
+    /// [`IdGenerator`] returns an id already in use.
+    pub fn create(
+        mut ps: PetState,
+        name: impl Into<String>,
+        mammal: MammalData,
+        dog: DogData,
+        working_dog: WorkingDogData,
+        herding: HerdingDogData,
+    ) -> (PetState, String) {
+        let id = ps.create_herding_dog(name, mammal, dog, working_dog, herding);
+        (ps, id)
+    }
+}
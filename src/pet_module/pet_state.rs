@@ -13,22 +13,65 @@
 //!
 //! ```text
 //! PetComponent      → Pet table (id, name)
-//! MammalComponent   → Mammal table (pet_id, hair_color, breed, has_hair)
+//! MammalComponent   → Mammal table (pet_id, hair_color, breed NULL, has_hair)
 //! DogComponent      → Dog table (mammal_id, tail_length, num_commands_known)
 //! CatComponent      → Cat table (mammal_id, declawed, sits_on_keyboard)
+//! HamsterComponent  → Hamster table (mammal_id, is_dwarf, hoards_food)
+//! ReptileComponent  → Reptile table (pet_id, scale_color, is_poisonous)
+//! TurtleComponent   → Turtle table (reptile_id, is_aquatic, is_soft_shelled)
+//! SnakeComponent    → Snake table (reptile_id, length)
 //! ```
 //!
 //! # Extension Components
 //!
-//! The module includes placeholder components (`ReptileComponent`, `TurtleComponent`,
-//! `SnakeComponent`) to demonstrate extensibility. These are registered but not
-//! yet implemented. See README section "Extending the Project" for exercises.
+//! Optional subsystems live in child modules (e.g. `lifecycle`) that add
+//! their own components and `impl PetState` blocks.
 
-use crate::pet_module::{Cat, CatData, Dog, DogData, Mammal, MammalData, PetData};
+#[cfg(feature = "compact")]
+use crate::pet_module::CompactMammals;
+use crate::pet_module::{
+    Aggregator, Cat, CatData, CatInput, ComponentDescription, Dog, DogData, DogInput,
+    EntityDescription, Hamster, HamsterData, IntoNullable, Mammal, MammalData, MammalFilter,
+    MammalPage, MammalView, NumericField, Order, PetData, PetError, PetPatch, PetRecord,
+    PetStateStats, PetView, Reptile, ReptileData, Snake, SnakeData, SortKey, Species, SpeciesData,
+    StorageReport, Turtle, TurtleData,
+};
+use specs::hibitset::{BitSetLike, BitSetNot};
 use specs::prelude::*;
 use specs_derive::Component;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::ops::RangeBounds;
 use uuid::Uuid;
 
+mod entry;
+mod extensions;
+mod fish;
+mod insurance;
+mod lifecycle;
+mod markers;
+mod query;
+mod sample_data;
+mod shelters;
+mod soft_delete;
+mod spec;
+mod working_dogs;
+#[cfg(feature = "world-access")]
+mod world_access;
+
+pub use entry::PetEntry;
+use fish::{BettaComponent, FishComponent, GoldfishComponent, TankComponent};
+use insurance::PolicyComponent;
+use lifecycle::LifecycleComponent;
+use markers::{AdoptedComponent, TrainedComponent, VaccinatedComponent};
+pub use query::{MammalQuery, PetQuery};
+use shelters::{HousedComponent, ShelterComponent};
+use soft_delete::DeletedComponent;
+pub use spec::Spec;
+use working_dogs::{HerdingDogComponent, ServiceDogComponent, WorkingDogComponent};
+
 // ============================================================================
 // ECS Components (Hidden Implementation Detail)
 // These map directly to database tables in Class Table Inheritance pattern
@@ -56,9 +99,37 @@ impl From<&PetComponent> for PetData {
     }
 }
 
+impl<'a> From<&'a PetComponent> for PetView<'a> {
+    fn from(comp: &'a PetComponent) -> Self {
+        Self {
+            uuid: &comp.uuid,
+            name: &comp.name,
+        }
+    }
+}
+
+impl From<PetComponent> for PetData {
+    fn from(comp: PetComponent) -> Self {
+        Self {
+            uuid: comp.uuid,
+            name: comp.name,
+        }
+    }
+}
+
+impl From<PetData> for PetComponent {
+    fn from(data: PetData) -> Self {
+        Self {
+            uuid: data.uuid,
+            name: data.name,
+        }
+    }
+}
+
 /// Component representing the Mammal table (intermediate hierarchy level).
 ///
-/// Maps to: `Mammal` table with columns (id, pet_id, hair_color, breed, has_hair)
+/// Maps to: `Mammal` table with columns (id, pet_id, hair_color, breed, has_hair),
+/// where `breed` is nullable
 ///
 /// This component extends `PetComponent` through entity composition,
 /// representing the middle level of the inheritance hierarchy.
@@ -66,7 +137,7 @@ impl From<&PetComponent> for PetData {
 #[storage(VecStorage)]
 struct MammalComponent {
     hair_color: String,
-    breed: String,
+    breed: Option<String>,
     has_hair: bool,
 }
 
@@ -80,31 +151,93 @@ impl From<&MammalComponent> for MammalData {
     }
 }
 
-/// Placeholder component for Reptile hierarchy (educational extension).
+impl<'a> From<&'a MammalComponent> for MammalView<'a> {
+    fn from(comp: &'a MammalComponent) -> Self {
+        Self {
+            hair_color: &comp.hair_color,
+            breed: comp.breed.as_deref(),
+            has_hair: comp.has_hair,
+        }
+    }
+}
+
+impl From<MammalComponent> for MammalData {
+    fn from(comp: MammalComponent) -> Self {
+        Self {
+            hair_color: comp.hair_color,
+            breed: comp.breed,
+            has_hair: comp.has_hair,
+        }
+    }
+}
+
+impl MammalComponent {
+    /// Builds the row, storing the hair color in normalized form.
+    fn new(hair_color: &str, breed: Option<String>, has_hair: bool) -> Self {
+        Self {
+            hair_color: normalize_hair_color(hair_color),
+            breed,
+            has_hair,
+        }
+    }
+}
+
+impl From<MammalData> for MammalComponent {
+    fn from(data: MammalData) -> Self {
+        Self::new(&data.hair_color, data.breed, data.has_hair)
+    }
+}
+
+/// Insertion sequence number of a pet.
 ///
-/// Maps to: `Reptile` table with columns (id, pet_id, scale_color, is_poisonous)
+/// Not a column of any CTI table: it plays the role of an auto-increment
+/// surrogate key, giving `get_all_*` a creation order that does not depend
+/// on how the ECS reuses entity slots.
+#[derive(Component, Debug, Clone, Copy)]
+#[storage(VecStorage)]
+struct SequenceComponent {
+    seq: u64,
+}
+
+/// Component representing the Reptile table (middle level in hierarchy).
 ///
-/// This component is registered but not yet implemented, demonstrating
-/// how to extend the pattern to support multiple inheritance hierarchies.
-/// See README section "Extending the Project" → "Adding Different Hierarchies".
+/// Maps to: `Reptile` table with columns (id, pet_id, scale_color, is_poisonous)
 ///
-/// **Exercise:** Implement `Turtle` and `Snake` types using this component,
-/// similar to how `Dog` and `Cat` use `MammalComponent`.
+/// The second branch under `PetComponent`, playing the role `MammalComponent`
+/// plays for dogs and cats.
 #[derive(Component, Debug, Clone)]
 #[storage(VecStorage)]
-#[allow(dead_code)]
 struct ReptileComponent {
     scale_color: String,
     is_poisonous: bool,
 }
 
+impl From<&ReptileComponent> for ReptileData {
+    fn from(comp: &ReptileComponent) -> Self {
+        Self {
+            scale_color: comp.scale_color.clone(),
+            is_poisonous: comp.is_poisonous,
+        }
+    }
+}
+
+impl From<ReptileData> for ReptileComponent {
+    fn from(data: ReptileData) -> Self {
+        Self {
+            scale_color: data.scale_color,
+            is_poisonous: data.is_poisonous,
+        }
+    }
+}
+
 /// Component representing the Dog table (leaf level in hierarchy).
 ///
 /// Maps to: `Dog` table with columns (id, mammal_id, tail_length, num_commands_known)
 ///
 /// This is a concrete type at the leaf of the inheritance tree, representing
 /// dog-specific attributes. Combined with `PetComponent` and `MammalComponent`
-/// via entity composition to form a complete `Dog`.
+/// via entity composition to form a complete `Dog`. Working dogs add a
+/// fourth level below it (see `working_dogs`).
 #[derive(Component, Debug, Clone)]
 #[storage(VecStorage)]
 struct DogComponent {
@@ -121,6 +254,15 @@ impl From<&DogComponent> for DogData {
     }
 }
 
+impl From<DogData> for DogComponent {
+    fn from(data: DogData) -> Self {
+        Self {
+            tail_length: data.tail_length,
+            num_commands_known: data.num_commands_known,
+        }
+    }
+}
+
 /// Component representing the Cat table (leaf level in hierarchy).
 ///
 /// Maps to: `Cat` table with columns (id, mammal_id, declawed, sits_on_keyboard)
@@ -144,37 +286,326 @@ impl From<&CatComponent> for CatData {
     }
 }
 
-/// Placeholder component for Turtle type (educational extension).
+impl From<CatData> for CatComponent {
+    fn from(data: CatData) -> Self {
+        Self {
+            declawed: data.declawed,
+            sits_on_keyboard: data.sits_on_keyboard,
+        }
+    }
+}
+
+/// Component representing the Hamster table (leaf level in hierarchy).
 ///
-/// Maps to: `Turtle` table with columns (id, reptile_id, is_aquatic, is_soft_shelled)
+/// Maps to: `Hamster` table with columns (id, mammal_id, is_dwarf, hoards_food)
+///
+/// The third mammal leaf, combined with `PetComponent` and `MammalComponent`
+/// like `DogComponent` and `CatComponent`.
+#[derive(Component, Debug, Clone)]
+#[storage(VecStorage)]
+struct HamsterComponent {
+    is_dwarf: bool,
+    hoards_food: bool,
+}
+
+impl From<&HamsterComponent> for HamsterData {
+    fn from(comp: &HamsterComponent) -> Self {
+        Self {
+            is_dwarf: comp.is_dwarf,
+            hoards_food: comp.hoards_food,
+        }
+    }
+}
+
+impl From<HamsterData> for HamsterComponent {
+    fn from(data: HamsterData) -> Self {
+        Self {
+            is_dwarf: data.is_dwarf,
+            hoards_food: data.hoards_food,
+        }
+    }
+}
+
+/// Component representing the Turtle table (leaf level in hierarchy).
 ///
-/// Part of the suggested Reptile hierarchy extension. Would be used alongside
-/// `PetComponent` and `ReptileComponent` to form a complete `Turtle` type.
+/// Maps to: `Turtle` table with columns (id, reptile_id, is_aquatic, is_soft_shelled)
 ///
-/// **Exercise:** Implement `Turtle` struct in pet_module.rs and add creation/query methods.
+/// Combined with `PetComponent` and `ReptileComponent` via entity
+/// composition to form a complete `Turtle`.
 #[derive(Component, Debug, Clone)]
 #[storage(VecStorage)]
-#[allow(dead_code)]
 struct TurtleComponent {
     is_aquatic: bool,
     is_soft_shelled: bool,
 }
 
-/// Placeholder component for Snake type (educational extension).
+impl From<&TurtleComponent> for TurtleData {
+    fn from(comp: &TurtleComponent) -> Self {
+        Self {
+            is_aquatic: comp.is_aquatic,
+            is_soft_shelled: comp.is_soft_shelled,
+        }
+    }
+}
+
+impl From<TurtleData> for TurtleComponent {
+    fn from(data: TurtleData) -> Self {
+        Self {
+            is_aquatic: data.is_aquatic,
+            is_soft_shelled: data.is_soft_shelled,
+        }
+    }
+}
+
+/// Component representing the Snake table (leaf level in hierarchy).
 ///
 /// Maps to: `Snake` table with columns (id, reptile_id, length)
 ///
-/// Part of the suggested Reptile hierarchy extension. Would be used alongside
-/// `PetComponent` and `ReptileComponent` to form a complete `Snake` type.
-///
-/// **Exercise:** Implement `Snake` struct in pet_module.rs and add creation/query methods.
+/// Combined with `PetComponent` and `ReptileComponent` via entity
+/// composition to form a complete `Snake`.
 #[derive(Component, Debug, Clone)]
 #[storage(VecStorage)]
-#[allow(dead_code)]
 struct SnakeComponent {
     length: f64,
 }
 
+impl From<&SnakeComponent> for SnakeData {
+    fn from(comp: &SnakeComponent) -> Self {
+        Self {
+            length: comp.length,
+        }
+    }
+}
+
+impl From<SnakeData> for SnakeComponent {
+    fn from(data: SnakeData) -> Self {
+        Self {
+            length: data.length,
+        }
+    }
+}
+
+// ============================================================================
+// Injected Collaborators
+// ============================================================================
+
+/// Source of the identifiers assigned to newly created pets and shelters.
+///
+/// `PetState` uses random v4 UUIDs by default ([`UuidV4Generator`]). Tests
+/// can inject a deterministic generator through [`PetState::builder`].
+/// Any `FnMut() -> String` closure also works as a generator.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let mut next = 0;
+/// let ps = PetState::builder()
+///     .id_generator(move || {
+///         next += 1;
+///         format!("pet-{next}")
+///     })
+///     .build();
+///
+/// let (ps, first) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+/// let (_, second) = Cat::create(ps, "Tom", "gray", "tabby", true, false, true);
+/// assert_eq!((first.as_str(), second.as_str()), ("pet-1", "pet-2"));
+/// ```
+pub trait IdGenerator: Send + Sync {
+    /// Returns the identifier for the next record.
+    fn next_id(&mut self) -> String;
+}
+
+impl<F> IdGenerator for F
+where
+    F: FnMut() -> String + Send + Sync,
+{
+    fn next_id(&mut self) -> String {
+        self()
+    }
+}
+
+/// Default [`IdGenerator`] producing random v4 UUIDs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidV4Generator;
+
+impl IdGenerator for UuidV4Generator {
+    fn next_id(&mut self) -> String {
+        Uuid::new_v4().to_string()
+    }
+}
+
+/// Input size limits enforced by a [`PetState`].
+///
+/// Embedding the gateway in a server means names and breeds may come from
+/// untrusted clients; capping their length keeps oversized values out of
+/// storage and everything downstream of it. Lengths are counted in
+/// characters. `None` (the default) means unlimited.
+///
+/// Limits are checked whenever a name or breed is written: on create
+/// ([`PetState::try_add_dog`], [`PetState::add_dogs`], ...) and on update
+/// ([`PetState::update_dog`], [`PetState::rename_pet`], ...).
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let mut ps = PetState::builder()
+///     .options(PetStateOptions {
+///         max_name_len: Some(8),
+///         ..Default::default()
+///     })
+///     .build();
+///
+/// let dog = DogInput {
+///     name: "Sir Barksalot the Third".to_string(),
+///     hair_color: "brown".to_string(),
+///     breed: None,
+///     has_hair: true,
+///     tail_length: 10.0,
+///     num_commands_known: 3,
+/// };
+/// assert_eq!(
+///     ps.try_add_dog(dog),
+///     Err(PetError::InputTooLong { field: "name", len: 23, max: 8 })
+/// );
+/// assert!(ps.get_all_dogs().is_empty());
+/// ```
+///
+/// With `unique_names`, every insert path and every rename rejects a name
+/// that another pet already has:
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+///
+/// let mut ps = PetState::builder()
+///     .options(PetStateOptions {
+///         unique_names: true,
+///         ..Default::default()
+///     })
+///     .build();
+///
+/// let rex = ps.create_dog("Rex", "brown", "boxer", true, 10.0, 15);
+/// let tom = ps.create_cat("Tom", "gray", "tabby", true, false, true);
+///
+/// assert_eq!(ps.rename_pet(&tom, "Rex"), Err(PetError::DuplicateName("Rex".to_string())));
+/// assert_eq!(ps.rename_pet(&rex, "Rex"), Ok(()));
+/// assert_eq!(ps.get_pets_by_name("Tom").len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PetStateOptions {
+    /// Maximum length of `Pet.name`.
+    pub max_name_len: Option<usize>,
+    /// Maximum length of `Mammal.breed`.
+    pub max_breed_len: Option<usize>,
+    /// Rejects a second pet with an existing name, like a `UNIQUE`
+    /// constraint on `Pet.name`.
+    pub unique_names: bool,
+}
+
+impl PetStateOptions {
+    /// Environment variable read into [`max_name_len`](Self::max_name_len).
+    pub const MAX_NAME_LEN_VAR: &'static str = "PETS_MAX_NAME_LEN";
+    /// Environment variable read into [`max_breed_len`](Self::max_breed_len).
+    pub const MAX_BREED_LEN_VAR: &'static str = "PETS_MAX_BREED_LEN";
+    /// Environment variable read into [`unique_names`](Self::unique_names).
+    pub const UNIQUE_NAMES_VAR: &'static str = "PETS_UNIQUE_NAMES";
+
+    /// Reads the options from environment variables.
+    ///
+    /// | Variable             | Field           |
+    /// |----------------------|-----------------|
+    /// | `PETS_MAX_NAME_LEN`  | `max_name_len`  |
+    /// | `PETS_MAX_BREED_LEN` | `max_breed_len` |
+    /// | `PETS_UNIQUE_NAMES`  | `unique_names`  |
+    ///
+    /// Unset or empty variables leave the limit or constraint off.
+    /// `PETS_UNIQUE_NAMES` accepts `true`/`false`, `1`/`0` or `yes`/`no`,
+    /// ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::InvalidConfig`] if a length variable is set to
+    /// something other than a non-negative integer, or `PETS_UNIQUE_NAMES`
+    /// to something other than a boolean.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// // SAFETY: no other thread reads the environment in this example.
+    /// unsafe { std::env::set_var(PetStateOptions::MAX_NAME_LEN_VAR, "32") };
+    ///
+    /// let options = PetStateOptions::from_env().unwrap();
+    /// assert_eq!(options.max_name_len, Some(32));
+    /// assert_eq!(options.max_breed_len, None);
+    /// assert!(!options.unique_names);
+    ///
+    /// unsafe { std::env::set_var(PetStateOptions::UNIQUE_NAMES_VAR, "yes") };
+    /// assert!(PetStateOptions::from_env().unwrap().unique_names);
+    ///
+    /// unsafe { std::env::set_var(PetStateOptions::UNIQUE_NAMES_VAR, "maybe") };
+    /// assert!(matches!(PetStateOptions::from_env(), Err(PetError::InvalidConfig { .. })));
+    /// ```
+    pub fn from_env() -> Result<Self, PetError> {
+        Ok(Self {
+            max_name_len: limit_from_env(Self::MAX_NAME_LEN_VAR)?,
+            max_breed_len: limit_from_env(Self::MAX_BREED_LEN_VAR)?,
+            unique_names: flag_from_env(Self::UNIQUE_NAMES_VAR)?,
+        })
+    }
+
+    /// Checks a name and breed against the configured limits.
+    fn check(&self, name: &str, breed: Option<&str>) -> Result<(), PetError> {
+        check_len("name", name, self.max_name_len)?;
+        match breed {
+            Some(breed) => check_len("breed", breed, self.max_breed_len),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses an optional length limit from the environment variable `key`.
+fn limit_from_env(key: &str) -> Result<Option<usize>, PetError> {
+    let value = std::env::var(key).unwrap_or_default();
+    if value.trim().is_empty() {
+        return Ok(None);
+    }
+    value
+        .trim()
+        .parse()
+        .map(Some)
+        .map_err(|_| PetError::InvalidConfig {
+            key: key.to_string(),
+            value,
+        })
+}
+
+/// Parses an optional boolean switch from the environment variable `key`.
+fn flag_from_env(key: &str) -> Result<bool, PetError> {
+    let value = std::env::var(key).unwrap_or_default();
+    match value.trim().to_ascii_lowercase().as_str() {
+        "" | "false" | "0" | "no" => Ok(false),
+        "true" | "1" | "yes" => Ok(true),
+        _ => Err(PetError::InvalidConfig {
+            key: key.to_string(),
+            value,
+        }),
+    }
+}
+
+/// Fails with [`PetError::InputTooLong`] if `value` is longer than `max`.
+fn check_len(field: &'static str, value: &str, max: Option<usize>) -> Result<(), PetError> {
+    let len = value.chars().count();
+    match max {
+        Some(max) if len > max => Err(PetError::InputTooLong { field, len, max }),
+        _ => Ok(()),
+    }
+}
+
 // ============================================================================
 // PetState: The Gateway/Data Access Layer
 // Encapsulates ECS implementation and provides clean domain API
@@ -203,6 +634,18 @@ struct SnakeComponent {
 /// ```
 pub struct PetState {
     pub(crate) ecs: World,
+    id_generator: Box<dyn IdGenerator>,
+    options: PetStateOptions,
+    include_deceased: bool,
+    next_sequence: u64,
+    /// Primary-key index over `PetComponent::uuid`, kept in step with every
+    /// insert and delete path.
+    by_uuid: HashMap<String, Entity>,
+    /// Secondary index over `PetComponent::name`.
+    by_name: NameIndex,
+    /// Primary-key index over shelter and tank UUIDs, which share the id
+    /// space with pets.
+    by_place_uuid: HashMap<String, Entity>,
 }
 
 impl Default for PetState {
@@ -211,6 +654,211 @@ impl Default for PetState {
     }
 }
 
+/// Inserts each mammal with [`PetState::insert`], keeping its UUID.
+///
+/// # Panics
+///
+/// Panics if `insert` fails: a UUID already present in the state, or a name
+/// or breed exceeding the [`PetStateOptions`].
+impl Extend<Mammal> for PetState {
+    fn extend<I: IntoIterator<Item = Mammal>>(&mut self, iter: I) {
+        for mammal in iter {
+            if let Err(err) = self.insert(mammal) {
+                panic!("{err}");
+            }
+        }
+    }
+}
+
+/// Consumes the state, yielding every visible mammal in creation order.
+///
+/// Components are moved out of storage rather than cloned, so this is the
+/// cheapest way to hand the whole population to a downstream pipeline.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::testing;
+///
+/// let ps = testing::sample_state();
+/// let names: Vec<String> = ps.into_iter().map(|m| m.name().to_string()).collect();
+///
+/// assert_eq!(names, ["Shippen", "Sophie", "Waterloo", "Berlioz", "Simba"]);
+/// ```
+impl IntoIterator for PetState {
+    type Item = Mammal;
+    type IntoIter = std::vec::IntoIter<Mammal>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let hidden = self.hidden_entities();
+        let mut fetched_dogs = self.ecs.write_storage::<DogComponent>();
+        let mut fetched_cats = self.ecs.write_storage::<CatComponent>();
+        let mut fetched_hamsters = self.ecs.write_storage::<HamsterComponent>();
+        let mut fetched_pets = self.ecs.write_storage::<PetComponent>();
+        let mut fetched_mammals = self.ecs.write_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+
+        let mut mammals: Vec<(Option<u64>, Mammal)> = (
+            fetched_dogs.drain(),
+            fetched_mammals.drain(),
+            fetched_pets.drain(),
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .map(|(dog, mammal, pet, sequence, _)| {
+                let dog = Mammal::Dog(Dog {
+                    pet: pet.into(),
+                    mammal: mammal.into(),
+                    dog_specific: (&dog).into(),
+                });
+                (sequence.map(|s| s.seq), dog)
+            })
+            .collect();
+        mammals.extend(
+            (
+                fetched_cats.drain(),
+                fetched_mammals.drain(),
+                fetched_pets.drain(),
+                fetched_sequences.maybe(),
+                !&hidden,
+            )
+                .join()
+                .map(|(cat, mammal, pet, sequence, _)| {
+                    let cat = Mammal::Cat(Cat {
+                        pet: pet.into(),
+                        mammal: mammal.into(),
+                        cat_specific: (&cat).into(),
+                    });
+                    (sequence.map(|s| s.seq), cat)
+                }),
+        );
+        mammals.extend(
+            (
+                fetched_hamsters.drain(),
+                fetched_mammals.drain(),
+                fetched_pets.drain(),
+                fetched_sequences.maybe(),
+                !&hidden,
+            )
+                .join()
+                .map(|(hamster, mammal, pet, sequence, _)| {
+                    let hamster = Mammal::Hamster(Hamster {
+                        pet: pet.into(),
+                        mammal: mammal.into(),
+                        hamster_specific: (&hamster).into(),
+                    });
+                    (sequence.map(|s| s.seq), hamster)
+                }),
+        );
+        mammals.sort_by_key(|(seq, _)| *seq);
+        mammals
+            .into_iter()
+            .map(|(_, mammal)| mammal)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// Builds a default [`PetState`] holding the given mammals.
+///
+/// # Panics
+///
+/// Panics if two mammals share a UUID.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::*;
+/// use ecs_inheritance_patterns::testing;
+///
+/// let mammals = testing::sample_state().get_all_mammals();
+/// let ps: PetState = mammals.clone().into_iter().collect();
+///
+/// assert_eq!(ps.get_all_mammals(), mammals);
+/// ```
+impl FromIterator<Mammal> for PetState {
+    fn from_iter<I: IntoIterator<Item = Mammal>>(iter: I) -> Self {
+        let mut ps = PetState::new();
+        ps.extend(iter);
+        ps
+    }
+}
+
+/// Builder for a [`PetState`] with injected collaborators.
+///
+/// Obtained from [`PetState::builder`]. Anything not configured falls back
+/// to the same defaults as [`PetState::new`].
+pub struct PetStateBuilder {
+    id_generator: Box<dyn IdGenerator>,
+    options: PetStateOptions,
+}
+
+impl Default for PetStateBuilder {
+    fn default() -> Self {
+        Self {
+            id_generator: Box::new(UuidV4Generator),
+            options: PetStateOptions::default(),
+        }
+    }
+}
+
+impl PetStateBuilder {
+    /// Sets the generator used to assign identifiers to new pets.
+    pub fn id_generator(mut self, id_generator: impl IdGenerator + 'static) -> Self {
+        self.id_generator = Box::new(id_generator);
+        self
+    }
+
+    /// Sets the input size limits.
+    pub fn options(mut self, options: PetStateOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Builds the `PetState`, registering all component types.
+    pub fn build(self) -> PetState {
+        let mut ps = PetState {
+            ecs: World::new(),
+            id_generator: self.id_generator,
+            options: self.options,
+            include_deceased: false,
+            next_sequence: 0,
+            by_uuid: HashMap::new(),
+            by_name: NameIndex::new(),
+            by_place_uuid: HashMap::new(),
+        };
+
+        // Register all component types with the ECS world
+        ps.ecs.register::<PetComponent>();
+        ps.ecs.register::<MammalComponent>();
+        ps.ecs.register::<ReptileComponent>();
+        ps.ecs.register::<DogComponent>();
+        ps.ecs.register::<CatComponent>();
+        ps.ecs.register::<HamsterComponent>();
+        ps.ecs.register::<TurtleComponent>();
+        ps.ecs.register::<SnakeComponent>();
+        ps.ecs.register::<FishComponent>();
+        ps.ecs.register::<GoldfishComponent>();
+        ps.ecs.register::<BettaComponent>();
+        ps.ecs.register::<TankComponent>();
+        ps.ecs.register::<WorkingDogComponent>();
+        ps.ecs.register::<ServiceDogComponent>();
+        ps.ecs.register::<HerdingDogComponent>();
+        ps.ecs.register::<TrainedComponent>();
+        ps.ecs.register::<VaccinatedComponent>();
+        ps.ecs.register::<AdoptedComponent>();
+        ps.ecs.register::<LifecycleComponent>();
+        ps.ecs.register::<PolicyComponent>();
+        ps.ecs.register::<ShelterComponent>();
+        ps.ecs.register::<HousedComponent>();
+        ps.ecs.register::<DeletedComponent>();
+        ps.ecs.register::<SequenceComponent>();
+
+        ps
+    }
+}
+
 impl PetState {
     /// Creates a new `PetState` with all component types registered.
     ///
@@ -226,18 +874,25 @@ impl PetState {
     /// // Ready to add pets
     /// ```
     pub fn new() -> Self {
-        let mut ps = PetState { ecs: World::new() };
-
-        // Register all component types with the ECS world
-        ps.ecs.register::<PetComponent>();
-        ps.ecs.register::<MammalComponent>();
-        ps.ecs.register::<ReptileComponent>();
-        ps.ecs.register::<DogComponent>();
-        ps.ecs.register::<CatComponent>();
-        ps.ecs.register::<TurtleComponent>();
-        ps.ecs.register::<SnakeComponent>();
+        Self::builder().build()
+    }
 
-        ps
+    /// Returns a builder for injecting collaborators such as the [`IdGenerator`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::builder()
+    ///     .id_generator(|| "fixed-id".to_string())
+    ///     .build();
+    ///
+    /// let (_, id) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// assert_eq!(id, "fixed-id");
+    /// ```
+    pub fn builder() -> PetStateBuilder {
+        PetStateBuilder::default()
     }
 
     // ========================================================================
@@ -246,6 +901,9 @@ impl PetState {
 
     /// Adds a dog to the ECS world, creating an entity with three components.
     ///
+    /// This is the primary creation API. [`Dog::create`] is the consuming
+    /// equivalent kept for the ownership-threading demonstration.
+    ///
     /// Creates an entity with:
     /// - `PetComponent` (base data: uuid, name)
     /// - `MammalComponent` (mammal data: hair_color, breed, has_hair)
@@ -257,7 +915,13 @@ impl PetState {
     ///
     /// Returns the UUID of the created dog for future reference.
     ///
-    /// # Database Equivalent
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the configured
+    /// [`PetStateOptions`], or if the [`IdGenerator`] returns an id already in
+    /// use; use [`try_add_dog`](Self::try_add_dog) to handle either as an error.
+    ///
+    /// # Database Equivalent
     ///
     /// ```sql
     /// INSERT INTO Pet (id, name) VALUES (uuid, 'Buddy');
@@ -266,42 +930,46 @@ impl PetState {
     /// INSERT INTO Dog (id, mammal_id, tail_length, num_commands_known)
     ///   VALUES (uuid3, uuid2, 10.5, 15);
     /// ```
-    pub(in crate::pet_module) fn add_dog(
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let ids: Vec<String> = ["Rex", "Fido"]
+    ///     .into_iter()
+    ///     .map(|name| ps.create_dog(name, "brown", "boxer", true, 10.0, 15))
+    ///     .collect();
+    ///
+    /// assert_eq!(ids.len(), 2);
+    /// assert_eq!(ps.get_all_dogs().len(), 2);
+    /// ```
+    pub fn create_dog(
         &mut self,
         name: impl Into<String>,
         hair_color: impl Into<String>,
-        breed: impl Into<String>,
+        breed: impl IntoNullable<String>,
         has_hair: bool,
         tail_length: f64,
         num_commands_known: i32,
     ) -> String {
-        let uuid = Uuid::new_v4().to_string();
-        let name = name.into();
-        let hair_color = hair_color.into();
-        let breed = breed.into();
-
-        self.ecs
-            .create_entity()
-            .with(PetComponent {
-                uuid: uuid.clone(),
-                name,
-            })
-            .with(MammalComponent {
-                hair_color,
-                breed,
-                has_hair,
-            })
-            .with(DogComponent {
-                tail_length,
-                num_commands_known,
-            })
-            .build();
-
-        uuid
+        let dog = DogInput {
+            name: name.into(),
+            hair_color: hair_color.into(),
+            breed: breed.into_nullable(),
+            has_hair,
+            tail_length,
+            num_commands_known,
+        };
+        self.try_add_dog(dog).unwrap_or_else(|err| panic!("{err}"))
     }
 
     /// Adds a cat to the ECS world, creating an entity with three components.
     ///
+    /// This is the primary creation API. [`Cat::create`] is the consuming
+    /// equivalent kept for the ownership-threading demonstration.
+    ///
     /// Creates an entity with:
     /// - `PetComponent` (base data: uuid, name)
     /// - `MammalComponent` (mammal data: hair_color, breed, has_hair)
@@ -313,6 +981,12 @@ impl PetState {
     ///
     /// Returns the UUID of the created cat for future reference.
     ///
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the configured
+    /// [`PetStateOptions`], or if the [`IdGenerator`] returns an id already in
+    /// use; use [`try_add_cat`](Self::try_add_cat) to handle either as an error.
+    ///
     /// # Database Equivalent
     ///
     /// ```sql
@@ -322,56 +996,126 @@ impl PetState {
     /// INSERT INTO Cat (id, mammal_id, declawed, sits_on_keyboard)
     ///   VALUES (uuid3, uuid2, false, true);
     /// ```
-    pub(in crate::pet_module) fn add_cat(
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let tom = ps.create_cat("Tom", "gray", None, true, false, true);
+    /// assert_eq!(ps.get_all_cats()[0].pet.uuid, tom);
+    /// ```
+    pub fn create_cat(
         &mut self,
         name: impl Into<String>,
         hair_color: impl Into<String>,
-        breed: impl Into<String>,
+        breed: impl IntoNullable<String>,
         has_hair: bool,
         declawed: bool,
         sits_on_keyboard: bool,
     ) -> String {
-        let uuid = Uuid::new_v4().to_string();
+        let cat = CatInput {
+            name: name.into(),
+            hair_color: hair_color.into(),
+            breed: breed.into_nullable(),
+            has_hair,
+            declawed,
+            sits_on_keyboard,
+        };
+        self.try_add_cat(cat).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Adds a hamster to the ECS world, creating an entity with three components.
+    ///
+    /// Hamster counterpart of [`create_cat`](Self::create_cat): the entity
+    /// carries `PetComponent`, `MammalComponent` and `HamsterComponent`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the UUID of the created hamster for future reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the configured
+    /// [`PetStateOptions`], or if the [`IdGenerator`] returns an id already in
+    /// use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let nibbles = ps.create_hamster("Nibbles", "golden", "syrian", true, false, true);
+    /// assert_eq!(ps.get_all_hamsters()[0].pet.uuid, nibbles);
+    /// assert!(ps.get_all_mammals()[0].is_hamster());
+    /// ```
+    pub fn create_hamster(
+        &mut self,
+        name: impl Into<String>,
+        hair_color: impl Into<String>,
+        breed: impl IntoNullable<String>,
+        has_hair: bool,
+        is_dwarf: bool,
+        hoards_food: bool,
+    ) -> String {
         let name = name.into();
         let hair_color = hair_color.into();
-        let breed = breed.into();
+        let breed = breed.into_nullable();
+        if let Err(err) = self.check_new_pet(&name, breed.as_deref()) {
+            panic!("{err}");
+        }
+        let uuid = self.next_free_id().unwrap_or_else(|err| panic!("{err}"));
+        let sequence = self.next_sequence();
 
-        self.ecs
+        let entity = self
+            .ecs
             .create_entity()
             .with(PetComponent {
                 uuid: uuid.clone(),
-                name,
-            })
-            .with(MammalComponent {
-                hair_color,
-                breed,
-                has_hair,
+                name: name.clone(),
             })
-            .with(CatComponent {
-                declawed,
-                sits_on_keyboard,
+            .with(MammalComponent::new(&hair_color, breed, has_hair))
+            .with(HamsterComponent {
+                is_dwarf,
+                hoards_food,
             })
+            .with(sequence)
             .build();
+        self.by_uuid.insert(uuid.clone(), entity);
+        index_name(&mut self.by_name, name, entity);
 
         uuid
     }
 
-    // ========================================================================
-    // Read Operations (Query)
-    // These methods demonstrate the "Gateway" pattern and lazy loading concept
-    // ========================================================================
-
-    /// Retrieves all dogs from the ECS world.
+    /// Adds a turtle to the ECS world, creating an entity with three components.
     ///
-    /// Performs a join query across Pet, Mammal, and Dog components,
-    /// simulating a SQL JOIN across three tables in Class Table Inheritance.
+    /// Creates an entity with:
+    /// - `PetComponent` (base data: uuid, name)
+    /// - `ReptileComponent` (reptile data: scale_color, is_poisonous)
+    /// - `TurtleComponent` (turtle-specific: is_aquatic, is_soft_shelled)
     ///
-    /// # SQL Equivalent
+    /// Turtles share the Pet table with dogs and cats but have no Mammal
+    /// row, so mammal queries never see them.
+    ///
+    /// # Returns
+    ///
+    /// Returns the UUID of the created turtle for future reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name exceeds the configured [`PetStateOptions`], or if the
+    /// [`IdGenerator`] returns an id already in use.
+    ///
+    /// # Database Equivalent
     ///
     /// ```sql
-    /// SELECT * FROM Pet
-    /// INNER JOIN Mammal ON Pet.id = Mammal.pet_id
-    /// INNER JOIN Dog ON Mammal.id = Dog.mammal_id
+    /// INSERT INTO Pet (id, name) VALUES (uuid, 'Shelly');
+    /// INSERT INTO Reptile (id, pet_id, scale_color, is_poisonous)
+    ///   VALUES (uuid2, uuid, 'olive', false);
+    /// INSERT INTO Turtle (id, reptile_id, is_aquatic, is_soft_shelled)
+    ///   VALUES (uuid3, uuid2, true, false);
     /// ```
     ///
     /// # Example
@@ -379,67 +1123,231 @@ impl PetState {
     /// ```
     /// use ecs_inheritance_patterns::pet_module::*;
     ///
-    /// let ps = PetState::new();
-    /// let (ps, _) = Dog::create(ps, "Buddy", "brown", "mutt", true, 8.0, 10);
-    /// let (ps, _) = Dog::create(ps, "Max", "black", "lab", true, 12.0, 20);
+    /// let mut ps = PetState::new();
+    /// let shelly = ps.create_turtle("Shelly", "olive", false, true, false);
+    /// assert_eq!(ps.get_all_turtles()[0].pet.uuid, shelly);
+    /// assert!(ps.get_all_mammals().is_empty());
+    /// ```
+    pub fn create_turtle(
+        &mut self,
+        name: impl Into<String>,
+        scale_color: impl Into<String>,
+        is_poisonous: bool,
+        is_aquatic: bool,
+        is_soft_shelled: bool,
+    ) -> String {
+        let name = name.into();
+        if let Err(err) = self.check_new_pet(&name, None) {
+            panic!("{err}");
+        }
+        let uuid = self.next_free_id().unwrap_or_else(|err| panic!("{err}"));
+        let sequence = self.next_sequence();
+
+        let entity = self
+            .ecs
+            .create_entity()
+            .with(PetComponent {
+                uuid: uuid.clone(),
+                name: name.clone(),
+            })
+            .with(ReptileComponent {
+                scale_color: scale_color.into(),
+                is_poisonous,
+            })
+            .with(TurtleComponent {
+                is_aquatic,
+                is_soft_shelled,
+            })
+            .with(sequence)
+            .build();
+        self.by_uuid.insert(uuid.clone(), entity);
+        index_name(&mut self.by_name, name, entity);
+
+        uuid
+    }
+
+    /// Adds a snake to the ECS world, creating an entity with three components.
+    ///
+    /// Snake counterpart of [`create_turtle`](Self::create_turtle): the
+    /// entity carries `PetComponent`, `ReptileComponent` and `SnakeComponent`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the UUID of the created snake for future reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name exceeds the configured [`PetStateOptions`], or if the
+    /// [`IdGenerator`] returns an id already in use.
+    ///
+    /// # Example
     ///
-    /// let dogs = ps.get_all_dogs();
-    /// assert_eq!(dogs.len(), 2);
     /// ```
-    pub fn get_all_dogs(&self) -> Vec<Dog> {
-        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
-        let fetched_pets = self.ecs.read_storage::<PetComponent>();
-        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let kaa = ps.create_snake("Kaa", "yellow", false, 3.5);
+    /// assert_eq!(ps.get_all_snakes()[0].pet.uuid, kaa);
+    /// ```
+    pub fn create_snake(
+        &mut self,
+        name: impl Into<String>,
+        scale_color: impl Into<String>,
+        is_poisonous: bool,
+        length: f64,
+    ) -> String {
+        let name = name.into();
+        if let Err(err) = self.check_new_pet(&name, None) {
+            panic!("{err}");
+        }
+        let uuid = self.next_free_id().unwrap_or_else(|err| panic!("{err}"));
+        let sequence = self.next_sequence();
 
-        // Join query - only entities with all three components
-        // Using From trait implementations for cleaner conversions
-        (&fetched_dogs, &fetched_mammals, &fetched_pets)
-            .join()
-            .map(|(dog, mammal, pet)| Dog {
-                pet: pet.into(),
-                mammal: mammal.into(),
-                dog_specific: dog.into(),
+        let entity = self
+            .ecs
+            .create_entity()
+            .with(PetComponent {
+                uuid: uuid.clone(),
+                name: name.clone(),
             })
-            .collect()
+            .with(ReptileComponent {
+                scale_color: scale_color.into(),
+                is_poisonous,
+            })
+            .with(SnakeComponent { length })
+            .with(sequence)
+            .build();
+        self.by_uuid.insert(uuid.clone(), entity);
+        index_name(&mut self.by_name, name, entity);
+
+        uuid
     }
 
-    /// Retrieves all cats from the ECS world.
+    /// Adds a dog, checking its name and breed against the [`PetStateOptions`].
     ///
-    /// Similar to [`get_all_dogs`](Self::get_all_dogs), performs a join query
-    /// across Pet, Mammal, and Cat components.
+    /// # Errors
+    ///
+    /// Returns [`PetError::InputTooLong`] if a configured limit is exceeded,
+    /// [`PetError::DuplicateName`] if the name is taken and
+    /// [`unique_names`](PetStateOptions::unique_names) is enabled, or
+    /// [`PetError::DuplicateId`] if the [`IdGenerator`] returns an id already
+    /// in use. Nothing is inserted in any of these cases.
     ///
     /// # Example
     ///
     /// ```
     /// use ecs_inheritance_patterns::pet_module::*;
     ///
-    /// let ps = PetState::new();
-    /// let (ps, _) = Cat::create(ps, "Whiskers", "orange", "tabby", true, false, true);
-    /// let (ps, _) = Cat::create(ps, "Shadow", "black", "bombay", true, true, false);
+    /// let mut ps = PetState::new();
+    /// let id = ps
+    ///     .try_add_dog(DogInput {
+    ///         name: "Rex".to_string(),
+    ///         hair_color: "brown".to_string(),
+    ///         breed: Some("boxer".to_string()),
+    ///         has_hair: true,
+    ///         tail_length: 10.0,
+    ///         num_commands_known: 15,
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(ps.get_all_dogs()[0].pet.uuid, id);
+    /// ```
+    ///
+    /// A generator that repeats itself is caught instead of overwriting the
+    /// first pet's index entry:
     ///
-    /// let cats = ps.get_all_cats();
-    /// assert_eq!(cats.len(), 2);
     /// ```
-    pub fn get_all_cats(&self) -> Vec<Cat> {
-        let fetched_cats = self.ecs.read_storage::<CatComponent>();
-        let fetched_pets = self.ecs.read_storage::<PetComponent>();
-        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::builder().id_generator(|| "same".to_string()).build();
+    /// let dog = |name: &str| DogInput {
+    ///     name: name.to_string(),
+    ///     hair_color: "brown".to_string(),
+    ///     breed: None,
+    ///     has_hair: true,
+    ///     tail_length: 10.0,
+    ///     num_commands_known: 15,
+    /// };
+    ///
+    /// ps.try_add_dog(dog("Rex")).unwrap();
+    /// assert_eq!(ps.try_add_dog(dog("Fido")), Err(PetError::DuplicateId("same".into())));
+    /// assert_eq!(ps.get_all_dogs().len(), 1);
+    /// ```
+    pub fn try_add_dog(&mut self, dog: DogInput) -> Result<String, PetError> {
+        self.check_new_pet(&dog.name, dog.breed.as_deref())?;
+        let uuid = self.next_free_id()?;
+        let sequence = self.next_sequence();
 
-        // Join query - using From trait implementations for cleaner conversions
-        (&fetched_cats, &fetched_mammals, &fetched_pets)
-            .join()
-            .map(|(cat, mammal, pet)| Cat {
-                pet: pet.into(),
-                mammal: mammal.into(),
-                cat_specific: cat.into(),
+        let entity = self
+            .ecs
+            .create_entity()
+            .with(PetComponent {
+                uuid: uuid.clone(),
+                name: dog.name.clone(),
             })
-            .collect()
+            .with(MammalComponent::new(
+                &dog.hair_color,
+                dog.breed,
+                dog.has_hair,
+            ))
+            .with(DogComponent {
+                tail_length: dog.tail_length,
+                num_commands_known: dog.num_commands_known,
+            })
+            .with(sequence)
+            .build();
+        self.by_uuid.insert(uuid.clone(), entity);
+        index_name(&mut self.by_name, dog.name, entity);
+
+        Ok(uuid)
     }
 
-    /// Retrieves all mammals as a polymorphic collection.
+    /// Adds a cat, checking its name and breed against the [`PetStateOptions`].
     ///
-    /// This demonstrates the solution to the `Vec<Mammal>` type safety concern
-    /// by using enum variants for type-safe polymorphism.
+    /// Cat counterpart of [`try_add_dog`](Self::try_add_dog).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`try_add_dog`](Self::try_add_dog).
+    pub fn try_add_cat(&mut self, cat: CatInput) -> Result<String, PetError> {
+        self.check_new_pet(&cat.name, cat.breed.as_deref())?;
+        let uuid = self.next_free_id()?;
+        let sequence = self.next_sequence();
+
+        let entity = self
+            .ecs
+            .create_entity()
+            .with(PetComponent {
+                uuid: uuid.clone(),
+                name: cat.name.clone(),
+            })
+            .with(MammalComponent::new(
+                &cat.hair_color,
+                cat.breed,
+                cat.has_hair,
+            ))
+            .with(CatComponent {
+                declawed: cat.declawed,
+                sits_on_keyboard: cat.sits_on_keyboard,
+            })
+            .with(sequence)
+            .build();
+        self.by_uuid.insert(uuid.clone(), entity);
+        index_name(&mut self.by_name, cat.name, entity);
+
+        Ok(uuid)
+    }
+
+    /// Stores a pre-built domain object, keeping its UUID.
+    ///
+    /// The inverse of a query: the [`Mammal`] is decomposed back into its
+    /// Pet, Mammal, and leaf components. Useful for values constructed
+    /// outside the state, e.g. received over the network.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::DuplicateId`] if a pet, shelter or tank with the
+    /// same UUID already exists, or [`PetError::InputTooLong`] if the name or breed exceeds the
+    /// [`PetStateOptions`]. Nothing is inserted in either case.
     ///
     /// # Example
     ///
@@ -447,86 +1355,3825 @@ impl PetState {
     /// use ecs_inheritance_patterns::pet_module::*;
     ///
     /// let ps = PetState::new();
-    /// let (ps, _) = Dog::create(ps, "Buddy", "brown", "retriever", true, 11.0, 15);
-    /// let (ps, _) = Cat::create(ps, "Mittens", "white", "persian", true, false, true);
-    /// let (ps, _) = Dog::create(ps, "Max", "black", "poodle", true, 8.0, 20);
+    /// let (source, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// let dog = source.get_all_dogs().remove(0);
     ///
-    /// let mammals = ps.get_all_mammals();
-    /// assert_eq!(mammals.len(), 3);
+    /// let mut target = PetState::new();
+    /// target.insert(Mammal::Dog(dog.clone())).unwrap();
+    /// assert_eq!(target.get_all_dogs(), [dog.clone()]);
+    /// assert_eq!(target.get_all_dogs()[0].pet.uuid, rex);
     ///
-    /// // All mammals share common interface
-    /// for mammal in mammals {
-    ///     println!("{} says {}", mammal.name(), mammal.make_sound());
-    /// }
+    /// assert_eq!(target.insert(Mammal::Dog(dog)), Err(PetError::DuplicateId(rex)));
     /// ```
-    pub fn get_all_mammals(&self) -> Vec<Mammal> {
-        // Collect all dogs and cats using iterator chaining
-        // More functional and efficient than creating intermediate collections
-        self.get_all_dogs()
-            .into_iter()
-            .map(Mammal::Dog)
-            .chain(self.get_all_cats().into_iter().map(Mammal::Cat))
-            .collect()
+    pub fn insert(&mut self, mammal: Mammal) -> Result<(), PetError> {
+        let (pet, mammal_data) = match &mammal {
+            Mammal::Dog(dog) => (&dog.pet, &dog.mammal),
+            Mammal::Cat(cat) => (&cat.pet, &cat.mammal),
+            Mammal::Hamster(hamster) => (&hamster.pet, &hamster.mammal),
+        };
+        if self.is_id_in_use(&pet.uuid) {
+            return Err(PetError::DuplicateId(pet.uuid.clone()));
+        }
+        self.check_new_pet(&pet.name, mammal_data.breed.as_deref())?;
+
+        let (uuid, name) = (pet.uuid.clone(), pet.name.clone());
+        let sequence = self.next_sequence();
+        let entity = match mammal {
+            Mammal::Dog(dog) => self
+                .ecs
+                .create_entity()
+                .with(PetComponent::from(dog.pet))
+                .with(MammalComponent::from(dog.mammal))
+                .with(DogComponent::from(dog.dog_specific))
+                .with(sequence)
+                .build(),
+            Mammal::Cat(cat) => self
+                .ecs
+                .create_entity()
+                .with(PetComponent::from(cat.pet))
+                .with(MammalComponent::from(cat.mammal))
+                .with(CatComponent::from(cat.cat_specific))
+                .with(sequence)
+                .build(),
+            Mammal::Hamster(hamster) => self
+                .ecs
+                .create_entity()
+                .with(PetComponent::from(hamster.pet))
+                .with(MammalComponent::from(hamster.mammal))
+                .with(HamsterComponent::from(hamster.hamster_specific))
+                .with(sequence)
+                .build(),
+        };
+        self.by_uuid.insert(uuid, entity);
+        index_name(&mut self.by_name, name, entity);
+        Ok(())
     }
 
-    /// Gets mammals filtered by hair color.
+    /// Inserts many dogs in one batch.
     ///
-    /// Demonstrates how business logic can be implemented at the gateway layer.
+    /// Fetches the component storages once for the whole batch instead of
+    /// once per dog, the ECS counterpart of a multi-row `INSERT`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the generated UUIDs, in input order.
+    ///
+    /// # Errors
+    ///
+    /// - [`PetError::InputTooLong`] if any row exceeds the configured
+    ///   [`PetStateOptions`]
+    /// - [`PetError::DuplicateName`] if
+    ///   [`unique_names`](PetStateOptions::unique_names) is set and a name is
+    ///   already taken or repeated within the batch
+    /// - [`PetError::DuplicateId`] if the [`IdGenerator`] returns an id
+    ///   already in use
+    ///
+    /// The batch is checked up front, so nothing is inserted on error.
     ///
     /// # Example
     ///
     /// ```
     /// use ecs_inheritance_patterns::pet_module::*;
     ///
-    /// let ps = PetState::new();
-    /// let (ps, _) = Dog::create(ps, "Goldie", "golden", "retriever", true, 12.0, 15);
-    /// let (ps, _) = Cat::create(ps, "Shadow", "black", "bombay", true, false, true);
-    /// let (ps, _) = Dog::create(ps, "Gold", "golden", "labrador", true, 10.0, 18);
+    /// let mut ps = PetState::new();
+    /// let ids = ps.add_dogs((0..1000).map(|i| DogInput {
+    ///     name: format!("Dog {i}"),
+    ///     hair_color: "brown".to_string(),
+    ///     breed: None,
+    ///     has_hair: true,
+    ///     tail_length: 10.0,
+    ///     num_commands_known: i,
+    /// }))
+    /// .unwrap();
     ///
-    /// let golden_mammals = ps.get_mammals_by_hair_color("golden");
-    /// assert_eq!(golden_mammals.len(), 2);
+    /// assert_eq!(ids.len(), 1000);
+    /// assert_eq!(ps.get_all_dogs().len(), 1000);
     /// ```
-    pub fn get_mammals_by_hair_color(&self, hair_color: &str) -> Vec<Mammal> {
-        self.get_all_mammals()
-            .into_iter()
-            .filter(|m| m.mammal_data().hair_color == hair_color)
-            .collect()
+    pub fn add_dogs(
+        &mut self,
+        dogs: impl IntoIterator<Item = DogInput>,
+    ) -> Result<Vec<String>, PetError> {
+        self.insert_mammal_rows(dogs.into_iter().map(|dog| {
+            (
+                dog.name,
+                MammalComponent::new(&dog.hair_color, dog.breed, dog.has_hair),
+                DogComponent {
+                    tail_length: dog.tail_length,
+                    num_commands_known: dog.num_commands_known,
+                },
+            )
+        }))
     }
 
-    /// Retrieves a specific dog by UUID (demonstrates lazy loading pattern).
+    /// Inserts many cats in one batch.
     ///
-    /// This method demonstrates how to implement efficient single-entity queries,
-    /// which would be the foundation for lazy loading patterns in larger applications.
+    /// Cat counterpart of [`add_dogs`](Self::add_dogs).
     ///
-    /// # Performance
+    /// # Example
     ///
-    /// Optimized to use `.find()` which stops at the first match, rather than
-    /// fetching all dogs and filtering. This is more efficient for large datasets.
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
     ///
-    /// # Returns
+    /// let mut ps = PetState::new();
+    /// let ids = ps.add_cats(["Tom", "Felix"].map(|name| CatInput {
+    ///     name: name.to_string(),
+    ///     hair_color: "gray".to_string(),
+    ///     breed: Some("tabby".to_string()),
+    ///     has_hair: true,
+    ///     declawed: false,
+    ///     sits_on_keyboard: true,
+    /// }))
+    /// .unwrap();
     ///
-    /// Returns `Some(Dog)` if found, `None` if no dog with that UUID exists.
+    /// let names: Vec<_> = ps.get_all_cats().into_iter().map(|cat| cat.pet.name).collect();
+    /// assert_eq!(ids.len(), 2);
+    /// assert_eq!(names, ["Tom", "Felix"]);
+    /// ```
+    pub fn add_cats(
+        &mut self,
+        cats: impl IntoIterator<Item = CatInput>,
+    ) -> Result<Vec<String>, PetError> {
+        self.insert_mammal_rows(cats.into_iter().map(|cat| {
+            (
+                cat.name,
+                MammalComponent::new(&cat.hair_color, cat.breed, cat.has_hair),
+                CatComponent {
+                    declawed: cat.declawed,
+                    sits_on_keyboard: cat.sits_on_keyboard,
+                },
+            )
+        }))
+    }
+
+    /// Creates one entity per `(name, mammal, leaf)` row, holding the
+    /// storages for the whole batch.
     ///
-    /// # Example Use Case
+    /// All rows are checked against the options before any is inserted.
+    fn insert_mammal_rows<L: Component>(
+        &mut self,
+        rows: impl Iterator<Item = (String, MammalComponent, L)>,
+    ) -> Result<Vec<String>, PetError> {
+        let rows: Vec<_> = rows.collect();
+        let mut batch_names = HashSet::new();
+        for (name, mammal, _) in &rows {
+            self.check_new_pet(name, mammal.breed.as_deref())?;
+            if self.options.unique_names && !batch_names.insert(name.as_str()) {
+                return Err(PetError::DuplicateName(name.clone()));
+            }
+        }
+        let mut uuids = Vec::with_capacity(rows.len());
+        let mut batch_ids = HashSet::new();
+        for _ in &rows {
+            let uuid = self.next_free_id()?;
+            if !batch_ids.insert(uuid.clone()) {
+                return Err(PetError::DuplicateId(uuid));
+            }
+            uuids.push(uuid);
+        }
+
+        let entities = self.ecs.entities();
+        let mut fetched_pets = self.ecs.write_storage::<PetComponent>();
+        let mut fetched_mammals = self.ecs.write_storage::<MammalComponent>();
+        let mut fetched_leaves = self.ecs.write_storage::<L>();
+        let mut fetched_sequences = self.ecs.write_storage::<SequenceComponent>();
+
+        for ((name, mammal, leaf), uuid) in rows.into_iter().zip(&uuids) {
+            let seq = self.next_sequence;
+            self.next_sequence += 1;
+            let entity = entities.create();
+            // Inserting into a freshly created entity cannot fail.
+            let _ = fetched_pets.insert(
+                entity,
+                PetComponent {
+                    uuid: uuid.clone(),
+                    name: name.clone(),
+                },
+            );
+            let _ = fetched_mammals.insert(entity, mammal);
+            let _ = fetched_leaves.insert(entity, leaf);
+            let _ = fetched_sequences.insert(entity, SequenceComponent { seq });
+            self.by_uuid.insert(uuid.clone(), entity);
+            index_name(&mut self.by_name, name, entity);
+        }
+        Ok(uuids)
+    }
+
+    /// Draws the next id from the [`IdGenerator`], failing with
+    /// [`PetError::DuplicateId`] if a pet, shelter or tank already has it.
     ///
-    /// In a web application, you might load a dog by ID from a URL parameter:
-    /// ```ignore
-    /// let dog = ps.get_dog_by_id(&url_param)?;
-    /// ```
-    #[allow(dead_code)]
-    pub(crate) fn get_dog_by_id(&self, uuid: &str) -> Option<Dog> {
-        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
-        let fetched_pets = self.ecs.read_storage::<PetComponent>();
-        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+    /// Every entity with a UUID allocates through here, so pets, shelters
+    /// and tanks never share an id.
+    fn next_free_id(&mut self) -> Result<String, PetError> {
+        let uuid = self.id_generator.next_id();
+        if self.is_id_in_use(&uuid) {
+            return Err(PetError::DuplicateId(uuid));
+        }
+        Ok(uuid)
+    }
 
-        // Direct query for specific UUID - stops at first match
-        (&fetched_dogs, &fetched_mammals, &fetched_pets)
-            .join()
-            .find(|(_, _, pet)| pet.uuid == uuid)
-            .map(|(dog, mammal, pet)| Dog {
-                pet: pet.into(),
-                mammal: mammal.into(),
-                dog_specific: dog.into(),
-            })
+    /// Returns `true` if a pet, shelter or tank already has `uuid`.
+    fn is_id_in_use(&self, uuid: &str) -> bool {
+        self.by_uuid.contains_key(uuid) || self.by_place_uuid.contains_key(uuid)
+    }
+
+    /// Checks a new pet's name and breed against the [`PetStateOptions`],
+    /// including name uniqueness.
+    fn check_new_pet(&self, name: &str, breed: Option<&str>) -> Result<(), PetError> {
+        self.options.check(name, breed)?;
+        self.check_name_free(name, None)
+    }
+
+    /// With [`unique_names`](PetStateOptions::unique_names) enabled, fails if
+    /// a pet other than `owner` already has `name`.
+    fn check_name_free(&self, name: &str, owner: Option<Entity>) -> Result<(), PetError> {
+        let taken = self
+            .by_name
+            .get(name)
+            .is_some_and(|entities| entities.iter().any(|&entity| Some(entity) != owner));
+        if self.options.unique_names && taken {
+            return Err(PetError::DuplicateName(name.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Hands out the next insertion sequence number.
+    fn next_sequence(&mut self) -> SequenceComponent {
+        let seq = self.next_sequence;
+        self.next_sequence += 1;
+        SequenceComponent { seq }
+    }
+
+    // ========================================================================
+    // Write Operations (Update)
+    // ========================================================================
+
+    /// Mutates an existing dog in place through its composed data structs.
+    ///
+    /// The closure receives copies of the Dog, Mammal, and Pet rows; whatever
+    /// it leaves in them is written back to the underlying components. The
+    /// `uuid` is the primary key and is never written back.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// UPDATE Dog SET num_commands_known = num_commands_known + 1
+    /// WHERE mammal_id = (SELECT id FROM Mammal WHERE pet_id = uuid);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no dog has that UUID, or
+    /// [`PetError::InputTooLong`] if the updated name or breed exceeds the
+    /// [`PetStateOptions`]; nothing is written in either case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// ps.update_dog(&rex, |dog, mammal, _pet| {
+    ///     dog.num_commands_known += 1;
+    ///     mammal.hair_color = "gray".to_string();
+    /// })
+    /// .unwrap();
+    ///
+    /// let dogs = ps.get_all_dogs();
+    /// assert_eq!(dogs[0].dog_specific.num_commands_known, 16);
+    /// assert_eq!(dogs[0].mammal.hair_color, "gray");
+    /// ```
+    pub fn update_dog(
+        &mut self,
+        uuid: &str,
+        f: impl FnOnce(&mut DogData, &mut MammalData, &mut PetData),
+    ) -> Result<(), PetError> {
+        self.update_mammal_leaf::<DogComponent, DogData>(uuid, f)
+    }
+
+    /// Mutates an existing cat in place through its composed data structs.
+    ///
+    /// Cat counterpart of [`update_dog`](Self::update_dog).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no cat has that UUID, or
+    /// [`PetError::InputTooLong`] if the updated name or breed exceeds the
+    /// [`PetStateOptions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, tom) = Cat::create(ps, "Tom", "gray", "tabby", true, false, true);
+    ///
+    /// ps.update_cat(&tom, |cat, _mammal, pet| {
+    ///     cat.sits_on_keyboard = false;
+    ///     pet.name = "Thomas".to_string();
+    /// })
+    /// .unwrap();
+    ///
+    /// let cats = ps.get_all_cats();
+    /// assert_eq!(cats[0].pet.name, "Thomas");
+    /// assert!(!cats[0].cat_specific.sits_on_keyboard);
+    ///
+    /// // Dogs are not cats
+    /// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// assert!(ps.update_cat(&rex, |_, _, _| {}).is_err());
+    /// ```
+    pub fn update_cat(
+        &mut self,
+        uuid: &str,
+        f: impl FnOnce(&mut CatData, &mut MammalData, &mut PetData),
+    ) -> Result<(), PetError> {
+        self.update_mammal_leaf::<CatComponent, CatData>(uuid, f)
+    }
+
+    /// Renames a pet, whatever its leaf type.
+    ///
+    /// The name lives in the shared Pet row, so this works for every species
+    /// without knowing which one the UUID belongs to.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// UPDATE Pet SET name = new_name WHERE id = uuid;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no pet has that UUID,
+    /// [`PetError::InputTooLong`] if the new name exceeds the
+    /// [`PetStateOptions`], or [`PetError::DuplicateName`] if another pet has
+    /// the name and names must be unique.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// let (mut ps, tom) = Cat::create(ps, "Tom", "gray", "tabby", true, false, true);
+    ///
+    /// ps.rename_pet(&rex, "Max").unwrap();
+    /// ps.rename_pet(&tom, "Thomas").unwrap();
+    /// assert!(ps.rename_pet("missing", "Nobody").is_err());
+    ///
+    /// assert_eq!(ps.get_all_dogs()[0].pet.name, "Max");
+    /// assert_eq!(ps.get_all_cats()[0].pet.name, "Thomas");
+    /// ```
+    pub fn rename_pet(&mut self, uuid: &str, new_name: impl Into<String>) -> Result<(), PetError> {
+        let new_name = new_name.into();
+        check_len("name", &new_name, self.options.max_name_len)?;
+        let entity = self
+            .find_entity(uuid)
+            .ok_or_else(|| PetError::PetNotFound(uuid.to_string()))?;
+        self.check_name_free(&new_name, Some(entity))?;
+
+        let mut fetched_pets = self.ecs.write_storage::<PetComponent>();
+        let pet = fetched_pets
+            .get_mut(entity)
+            .ok_or_else(|| PetError::PetNotFound(uuid.to_string()))?;
+        unindex_name(&mut self.by_name, &pet.name, entity);
+        index_name(&mut self.by_name, new_name.clone(), entity);
+        pet.name = new_name;
+        Ok(())
+    }
+
+    /// Replaces the Mammal row of any mammal, whatever its leaf type.
+    ///
+    /// The shared columns live in one component, so a single update covers
+    /// dogs and cats alike instead of one method per species.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// UPDATE Mammal SET hair_color = ?, breed = ?, has_hair = ?
+    /// WHERE pet_id = uuid;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no mammal has that UUID, or
+    /// [`PetError::InputTooLong`] if the breed exceeds the
+    /// [`PetStateOptions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let rex = ps.create_dog("Rex", "brown", "boxer", true, 10.0, 15);
+    /// let tom = ps.create_cat("Tom", "gray", "tabby", true, false, true);
+    ///
+    /// let hairless = MammalData {
+    ///     hair_color: "none".to_string(),
+    ///     breed: None,
+    ///     has_hair: false,
+    /// };
+    /// ps.set_mammal_data(&rex, hairless.clone()).unwrap();
+    /// ps.set_mammal_data(&tom, hairless.clone()).unwrap();
+    ///
+    /// assert!(ps.get_all_mammals().iter().all(|m| m.mammal_data() == &hairless));
+    /// ```
+    pub fn set_mammal_data(&mut self, uuid: &str, data: MammalData) -> Result<(), PetError> {
+        if let Some(breed) = &data.breed {
+            check_len("breed", breed, self.options.max_breed_len)?;
+        }
+        let not_found = || PetError::PetNotFound(uuid.to_string());
+        let entity = self.find_entity(uuid).ok_or_else(not_found)?;
+
+        let mut fetched_mammals = self.ecs.write_storage::<MammalComponent>();
+        let mammal = fetched_mammals.get_mut(entity).ok_or_else(not_found)?;
+        *mammal = data.into();
+        Ok(())
+    }
+
+    /// Applies `mutator` to every visible mammal matching `filter`.
+    ///
+    /// The set-based counterpart of [`set_mammal_data`](Self::set_mammal_data):
+    /// the mutator receives copies of each matching pet's Mammal and Pet rows
+    /// and its changes are written back. The `uuid` is never written back.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of pets updated.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// UPDATE Mammal SET has_hair = FALSE WHERE breed = 'sphynx';
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::InputTooLong`] if any updated name or breed exceeds
+    /// the [`PetStateOptions`]. Every row is checked before any is written,
+    /// so nothing changes in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::with_sample_data();
+    /// let updated = ps
+    ///     .update_where(&MammalFilter::Breed("sphynx".to_string()), |mammal, _pet| {
+    ///         mammal.hair_color = "pink".to_string();
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(updated, 2);
+    /// assert_eq!(ps.get_mammals_by_hair_color("pink").len(), 2);
+    /// ```
+    pub fn update_where(
+        &mut self,
+        filter: &MammalFilter,
+        mut mutator: impl FnMut(&mut MammalData, &mut PetData),
+    ) -> Result<usize, PetError> {
+        let entities = self.ecs.entities();
+        let mut fetched_pets = self.ecs.write_storage::<PetComponent>();
+        let mut fetched_mammals = self.ecs.write_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut updates = Vec::new();
+        let mut new_names = HashSet::new();
+        for (entity, mammal, pet, _) in
+            (&entities, &fetched_mammals, &fetched_pets, !&hidden).join()
+        {
+            if !filter_matches(filter, mammal) {
+                continue;
+            }
+            let mut mammal_data = MammalData::from(mammal);
+            let mut pet_data = PetData::from(pet);
+            mutator(&mut mammal_data, &mut pet_data);
+            self.options
+                .check(&pet_data.name, mammal_data.breed.as_deref())?;
+            if pet_data.name != pet.name {
+                self.check_name_free(&pet_data.name, Some(entity))?;
+                if self.options.unique_names && !new_names.insert(pet_data.name.clone()) {
+                    return Err(PetError::DuplicateName(pet_data.name));
+                }
+            }
+            updates.push((entity, mammal_data, pet_data.name));
+        }
+
+        let updated = updates.len();
+        for (entity, mammal_data, name) in updates {
+            if let Some(mammal) = fetched_mammals.get_mut(entity) {
+                *mammal = mammal_data.into();
+            }
+            if let Some(pet) = fetched_pets.get_mut(entity)
+                && pet.name != name
+            {
+                unindex_name(&mut self.by_name, &pet.name, entity);
+                index_name(&mut self.by_name, name.clone(), entity);
+                pet.name = name;
+            }
+        }
+        Ok(updated)
+    }
+
+    /// Overwrites only the columns a patch sets.
+    ///
+    /// Accepts a [`DogPatch`](crate::pet_module::DogPatch) or
+    /// [`CatPatch`](crate::pet_module::CatPatch) directly; see their docs for
+    /// field semantics.
+    ///
+    /// # Errors
+    ///
+    /// - [`PetError::PetNotFound`] if no pet of the patch's species has that
+    ///   UUID
+    /// - [`PetError::InputTooLong`] if the patched name or breed exceeds the
+    ///   [`PetStateOptions`]
+    /// - [`PetError::DuplicateName`] if the patch renames the pet to a taken
+    ///   name while [`unique_names`](PetStateOptions::unique_names) is set
+    ///
+    /// Nothing is written on error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, tom) = Cat::create(ps, "Tom", "gray", "tabby", true, false, true);
+    ///
+    /// let patch = CatPatch {
+    ///     hair_color: Some("white".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// ps.apply_patch(&tom, patch).unwrap();
+    /// assert_eq!(ps.get_all_cats()[0].mammal.hair_color, "white");
+    ///
+    /// // A dog patch never matches a cat
+    /// assert!(ps.apply_patch(&tom, DogPatch::default()).is_err());
+    /// ```
+    pub fn apply_patch(&mut self, uuid: &str, patch: impl Into<PetPatch>) -> Result<(), PetError> {
+        match patch.into() {
+            PetPatch::Dog(patch) => {
+                self.update_dog(uuid, |dog, mammal, pet| patch.apply(dog, mammal, pet))
+            }
+            PetPatch::Cat(patch) => {
+                self.update_cat(uuid, |cat, mammal, pet| patch.apply(cat, mammal, pet))
+            }
+        }
+    }
+
+    /// Changes a pet's leaf type, keeping its Pet and Mammal data.
+    ///
+    /// Removes the current leaf component, along with any working-dog rows
+    /// below it, and attaches the one described by `data`. In Class Table
+    /// Inheritance a subtype change is a `DELETE` from
+    /// one leaf table and an `INSERT` into another; the shared rows, the
+    /// UUID, and every relationship (policies, shelter) stay untouched.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// DELETE FROM Dog WHERE mammal_id = m_id;
+    /// INSERT INTO Cat (mammal_id, declawed, sits_on_keyboard) VALUES (m_id, false, true);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no mammal has that UUID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let id = ps.create_dog("Ambiguous", "gray", None, true, 6.0, 0);
+    ///
+    /// let cat = CatData { declawed: false, sits_on_keyboard: true };
+    /// ps.reclassify(&id, cat).unwrap();
+    ///
+    /// assert!(ps.get_all_dogs().is_empty());
+    /// let cats = ps.get_all_cats();
+    /// assert_eq!(cats[0].pet.uuid, id);
+    /// assert_eq!(cats[0].mammal.hair_color, "gray");
+    /// ```
+    ///
+    /// Reclassifying a working dog drops its subtype rows too, so they don't
+    /// come back if it later becomes a dog again:
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let id = ps.create_service_dog(
+    ///     "Buddy",
+    ///     MammalData { hair_color: "yellow".into(), breed: None, has_hair: true },
+    ///     DogData { tail_length: 11.0, num_commands_known: 40 },
+    ///     WorkingDogData { years_of_service: 3 },
+    ///     ServiceDogData { assists_with: "guide".into() },
+    /// );
+    ///
+    /// ps.reclassify(&id, CatData { declawed: false, sits_on_keyboard: true }).unwrap();
+    /// let tables: Vec<&str> = ps
+    ///     .describe_entity(&id)
+    ///     .unwrap()
+    ///     .components
+    ///     .iter()
+    ///     .map(|c| c.table)
+    ///     .collect();
+    /// assert_eq!(tables, ["Pet", "Mammal", "Cat"]);
+    ///
+    /// ps.reclassify(&id, DogData { tail_length: 11.0, num_commands_known: 40 }).unwrap();
+    /// assert!(ps.get_working_dog_by_id(&id).is_none());
+    /// ```
+    pub fn reclassify(&mut self, uuid: &str, data: impl Into<SpeciesData>) -> Result<(), PetError> {
+        let entity = self
+            .find_entity(uuid)
+            .filter(|&entity| self.ecs.read_storage::<MammalComponent>().contains(entity))
+            .ok_or_else(|| PetError::PetNotFound(uuid.to_string()))?;
+        self.remove_working_dog_rows(entity);
+
+        let mut fetched_dogs = self.ecs.write_storage::<DogComponent>();
+        let mut fetched_cats = self.ecs.write_storage::<CatComponent>();
+        let mut fetched_hamsters = self.ecs.write_storage::<HamsterComponent>();
+        fetched_dogs.remove(entity);
+        fetched_cats.remove(entity);
+        fetched_hamsters.remove(entity);
+
+        // Inserting into a live entity cannot fail.
+        let _ = match data.into() {
+            SpeciesData::Dog(dog) => fetched_dogs.insert(entity, dog.into()).map(drop),
+            SpeciesData::Cat(cat) => fetched_cats.insert(entity, cat.into()).map(drop),
+            SpeciesData::Hamster(hamster) => {
+                fetched_hamsters.insert(entity, hamster.into()).map(drop)
+            }
+        };
+        Ok(())
+    }
+
+    /// Read-modify-write shared by the per-species `update_*` methods.
+    ///
+    /// `L` is the leaf component and `D` its public data struct.
+    fn update_mammal_leaf<L, D>(
+        &mut self,
+        uuid: &str,
+        f: impl FnOnce(&mut D, &mut MammalData, &mut PetData),
+    ) -> Result<(), PetError>
+    where
+        L: Component + From<D>,
+        D: for<'a> From<&'a L>,
+    {
+        let not_found = || PetError::PetNotFound(uuid.to_string());
+        let entity = self.find_entity(uuid).ok_or_else(not_found)?;
+
+        let mut fetched_leaves = self.ecs.write_storage::<L>();
+        let mut fetched_mammals = self.ecs.write_storage::<MammalComponent>();
+        let mut fetched_pets = self.ecs.write_storage::<PetComponent>();
+
+        let (Some(leaf), Some(mammal), Some(pet)) = (
+            fetched_leaves.get(entity),
+            fetched_mammals.get_mut(entity),
+            fetched_pets.get_mut(entity),
+        ) else {
+            return Err(not_found());
+        };
+
+        let mut leaf_data = D::from(leaf);
+        let mut mammal_data = MammalData::from(&*mammal);
+        let mut pet_data = PetData::from(&*pet);
+
+        f(&mut leaf_data, &mut mammal_data, &mut pet_data);
+        self.options
+            .check(&pet_data.name, mammal_data.breed.as_deref())?;
+        if pet_data.name != pet.name {
+            self.check_name_free(&pet_data.name, Some(entity))?;
+            unindex_name(&mut self.by_name, &pet.name, entity);
+            index_name(&mut self.by_name, pet_data.name.clone(), entity);
+        }
+
+        *mammal = mammal_data.into();
+        pet.name = pet_data.name;
+        // Re-inserting replaces the leaf row regardless of its storage type.
+        fetched_leaves
+            .insert(entity, leaf_data.into())
+            .expect("entity was just looked up and is alive");
+        Ok(())
+    }
+
+    // ========================================================================
+    // Write Operations (Delete)
+    // ========================================================================
+
+    /// Deletes the pet with the given UUID, whatever its leaf type.
+    ///
+    /// Removes the entity together with every component attached to it,
+    /// the ECS equivalent of `ON DELETE CASCADE` across the hierarchy tables.
+    /// Rows in other tables that reference the pet (e.g. insurance policies)
+    /// are deleted as well. The freed entity slot is reused by the next pet
+    /// created.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a pet was deleted, `false` if no pet has that UUID.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// DELETE FROM Pet WHERE id = uuid;  -- cascades to Mammal and Dog/Cat
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, dog_id) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// assert!(ps.delete(&dog_id));
+    /// assert!(!ps.delete(&dog_id));
+    /// assert!(ps.get_all_dogs().is_empty());
+    /// ```
+    pub fn delete(&mut self, uuid: &str) -> bool {
+        match self.find_entity(uuid) {
+            Some(entity) => self.delete_pet_entity(entity),
+            None => false,
+        }
+    }
+
+    /// Deletes every visible mammal matching `predicate`.
+    ///
+    /// Each matching pet goes through the same cascade as
+    /// [`delete`](Self::delete). Pets hidden from queries (e.g. deceased
+    /// ones, by default) are never passed to the predicate.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of pets deleted.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// DELETE FROM Pet WHERE id IN (
+    ///     SELECT m.pet_id FROM Mammal m JOIN Cat c ON c.mammal_id = m.id
+    ///     WHERE c.declawed
+    /// );
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let deleted = ps.delete_where(|m| matches!(m, Mammal::Cat(cat) if cat.cat_specific.declawed));
+    ///
+    /// assert_eq!(deleted, 2);
+    /// assert!(ps.get_all_cats().is_empty());
+    /// assert_eq!(ps.get_all_dogs().len(), 3);
+    /// ```
+    pub fn delete_where(&mut self, predicate: impl Fn(&Mammal) -> bool) -> usize {
+        self.mammal_entities_where(predicate)
+            .into_iter()
+            .filter(|&entity| self.delete_pet_entity(entity))
+            .count()
+    }
+
+    /// Keeps only the visible mammals matching `predicate`, deleting the rest.
+    ///
+    /// Mirrors [`Vec::retain`]; it is the complement of
+    /// [`delete_where`](Self::delete_where), with the same cascade and the
+    /// same treatment of hidden pets (they are neither tested nor deleted).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// ps.retain(|mammal| mammal.hair_color() == "blond");
+    ///
+    /// assert_eq!(ps.get_all_mammals().len(), 3);
+    /// assert_eq!(ps.get_mammals_by_hair_color("blond").len(), 3);
+    /// ```
+    pub fn retain(&mut self, predicate: impl Fn(&Mammal) -> bool) {
+        self.delete_where(|mammal| !predicate(mammal));
+    }
+
+    /// Removes every entity: pets (fish included), insurance policies,
+    /// shelters, and tanks.
+    ///
+    /// Component registrations and configuration (ID generator, options,
+    /// visibility settings) are kept, so the state can be reused without
+    /// rebuilding the world.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// TRUNCATE Pet, Mammal, Dog, Cat, Fish, Goldfish, Betta, Policy, Shelter, Tank;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// ps.add_tank("Reef", WaterType::Salt);
+    /// ps.clear();
+    /// assert!(ps.get_all_mammals().is_empty());
+    /// assert!(ps.tanks().is_empty());
+    ///
+    /// let (ps, _) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// assert_eq!(ps.get_all_dogs().len(), 1);
+    /// ```
+    pub fn clear(&mut self) {
+        self.ecs.delete_all();
+        self.by_uuid.clear();
+        self.by_name.clear();
+        self.by_place_uuid.clear();
+    }
+
+    /// Removes every visible mammal and returns them.
+    ///
+    /// Like [`Vec::drain`], the pets are moved out rather than discarded:
+    /// the composed values are returned and the entities deleted with the
+    /// usual cascade. Handy for moving pets to another `PetState`. The
+    /// mammals come back in creation order, like
+    /// [`get_all_mammals`](Self::get_all_mammals).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut source = testing::sample_state();
+    /// let mut target = PetState::new();
+    ///
+    /// for mammal in source.drain_mammals() {
+    ///     target.insert(mammal).unwrap();
+    /// }
+    /// assert!(source.get_all_mammals().is_empty());
+    /// assert_eq!(target.get_all_mammals().len(), 5);
+    ///
+    /// let mut ps = PetState::new();
+    /// ps.create_cat("Tom", "gray", None, true, false, true);
+    /// ps.create_dog("Rex", "brown", None, true, 10.0, 3);
+    /// let names: Vec<_> = ps.drain_mammals().iter().map(|m| m.name().to_string()).collect();
+    /// assert_eq!(names, ["Tom", "Rex"]);
+    /// ```
+    pub fn drain_mammals(&mut self) -> Vec<Mammal> {
+        self.drain_matching(|_| true)
+    }
+
+    /// Removes every visible dog and returns them.
+    ///
+    /// Dog-only counterpart of [`drain_mammals`](Self::drain_mammals).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let dogs = ps.drain_dogs();
+    ///
+    /// assert_eq!(dogs.len(), 3);
+    /// assert!(ps.get_all_dogs().is_empty());
+    /// assert_eq!(ps.get_all_cats().len(), 2);
+    /// ```
+    pub fn drain_dogs(&mut self) -> Vec<Dog> {
+        self.drain_matching(|mammal| matches!(mammal, Mammal::Dog(_)))
+            .into_iter()
+            .filter_map(|mammal| match mammal {
+                Mammal::Dog(dog) => Some(dog),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Removes every visible cat and returns them.
+    ///
+    /// Cat-only counterpart of [`drain_mammals`](Self::drain_mammals).
+    pub fn drain_cats(&mut self) -> Vec<Cat> {
+        self.drain_matching(|mammal| matches!(mammal, Mammal::Cat(_)))
+            .into_iter()
+            .filter_map(|mammal| match mammal {
+                Mammal::Cat(cat) => Some(cat),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Deletes the visible mammals matching `keep` and returns them.
+    ///
+    /// The returned mammals are in creation order.
+    fn drain_matching(&mut self, keep: impl Fn(&Mammal) -> bool) -> Vec<Mammal> {
+        let mut matched = Vec::new();
+        self.visit_mammal_rows(|entity, _, leaf, mammal, pet| {
+            let mammal = leaf.compose(mammal, pet);
+            if keep(&mammal) {
+                matched.push((entity, mammal));
+            }
+        });
+
+        matched
+            .into_iter()
+            .filter(|&(entity, _)| self.delete_pet_entity(entity))
+            .map(|(_, mammal)| mammal)
+            .collect()
+    }
+
+    /// Removes the mammal with the given UUID and returns it.
+    ///
+    /// Unlike [`delete`](Self::delete), the data is handed back as the
+    /// composed domain object. Looks the pet up by UUID regardless of
+    /// visibility settings, like `delete`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` (and deletes nothing) if no mammal has that UUID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let tom = ps.create_cat("Tom", "gray", "tabby", true, false, true);
+    ///
+    /// let taken = ps.take_mammal(&tom).unwrap();
+    /// assert_eq!(taken.name(), "Tom");
+    /// assert!(ps.take_mammal(&tom).is_none());
+    /// ```
+    pub fn take_mammal(&mut self, uuid: &str) -> Option<Mammal> {
+        let entity = self.find_entity(uuid)?;
+        let mammal = self.mammal_at(entity)?;
+        self.delete_pet_entity(entity);
+        Some(mammal)
+    }
+
+    /// Removes the dog with the given UUID and returns it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no dog has that UUID; a cat with that UUID is left
+    /// in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let rex = ps.create_dog("Rex", "brown", "boxer", true, 10.0, 15);
+    /// let tom = ps.create_cat("Tom", "gray", "tabby", true, false, true);
+    ///
+    /// assert!(ps.take_dog(&tom).is_none());
+    /// assert_eq!(ps.take_dog(&rex).unwrap().pet.name, "Rex");
+    /// assert_eq!(ps.get_all_mammals().len(), 1);
+    /// ```
+    pub fn take_dog(&mut self, uuid: &str) -> Option<Dog> {
+        let entity = self.find_entity(uuid)?;
+        let Mammal::Dog(dog) = self.mammal_at(entity)? else {
+            return None;
+        };
+        self.delete_pet_entity(entity);
+        Some(dog)
+    }
+
+    /// Removes the cat with the given UUID and returns it.
+    ///
+    /// Cat counterpart of [`take_dog`](Self::take_dog).
+    pub fn take_cat(&mut self, uuid: &str) -> Option<Cat> {
+        let entity = self.find_entity(uuid)?;
+        let Mammal::Cat(cat) = self.mammal_at(entity)? else {
+            return None;
+        };
+        self.delete_pet_entity(entity);
+        Some(cat)
+    }
+
+    /// Deletes a pet entity and every entity that references it.
+    ///
+    /// All deletion paths go through here so foreign-key cascades stay in
+    /// one place.
+    fn delete_pet_entity(&mut self, entity: Entity) -> bool {
+        if let Some(pet) = self.ecs.read_storage::<PetComponent>().get(entity) {
+            self.by_uuid.remove(&pet.uuid);
+            unindex_name(&mut self.by_name, &pet.name, entity);
+        }
+        self.delete_policies_of(entity);
+        self.ecs.delete_entity(entity).is_ok()
+    }
+
+    /// Looks up the entity whose `PetComponent` carries `uuid`.
+    ///
+    /// Constant time through the UUID index, so every by-id operation
+    /// (get, update, delete) avoids scanning the Pet table.
+    fn find_entity(&self, uuid: &str) -> Option<Entity> {
+        self.by_uuid.get(uuid).copied()
+    }
+
+    /// Composes the mammal stored on `entity`, ignoring visibility settings.
+    fn mammal_at(&self, entity: Entity) -> Option<Mammal> {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_hamsters = self.ecs.read_storage::<HamsterComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+
+        let pet = fetched_pets.get(entity)?;
+        let mammal = fetched_mammals.get(entity)?;
+        let leaf = if let Some(dog) = fetched_dogs.get(entity) {
+            LeafRow::Dog(dog)
+        } else if let Some(cat) = fetched_cats.get(entity) {
+            LeafRow::Cat(cat)
+        } else {
+            LeafRow::Hamster(fetched_hamsters.get(entity)?)
+        };
+        Some(leaf.compose(mammal, pet))
+    }
+
+    /// Visible mammal entities whose composed domain object matches `predicate`.
+    fn mammal_entities_where(&self, predicate: impl Fn(&Mammal) -> bool) -> Vec<Entity> {
+        let mut matched = Vec::new();
+        self.visit_mammal_rows(|entity, _, leaf, mammal, pet| {
+            if predicate(&leaf.compose(mammal, pet)) {
+                matched.push(entity);
+            }
+        });
+        matched
+    }
+
+    /// Entities that collection queries skip under the current settings.
+    ///
+    /// Queries anti-join against this set (`!&hidden`), so hidden pets are
+    /// never composed into domain objects.
+    fn hidden_entities(&self) -> BitSet {
+        let mut hidden = self.hidden_by_settings();
+        self.add_soft_deleted_to(&mut hidden);
+        hidden
+    }
+
+    /// Visible entities that have a row in every one of `masks`.
+    ///
+    /// Lets lazy iterators walk matching rows by index without holding a
+    /// join that borrows its storages.
+    fn visible_rows(&self, masks: &[&BitSet]) -> BitSet {
+        let mut rows = BitSet::new();
+        if let Some((first, rest)) = masks.split_first() {
+            rows |= *first;
+            for mask in rest {
+                rows &= *mask;
+            }
+        }
+        rows &= &BitSetNot(self.hidden_entities());
+        rows
+    }
+
+    /// Orders row indices from [`visible_rows`](Self::visible_rows) by
+    /// insertion sequence, so lazy iterators match the `get_all_*` order
+    /// while still composing nothing up front.
+    fn rows_by_insertion(&self, rows: &BitSet) -> Vec<u32> {
+        let entities = self.ecs.entities();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let mut rows: Vec<u32> = rows.iter().collect();
+        rows.sort_by_key(|&id| fetched_sequences.get(entities.entity(id)).map(|s| s.seq));
+        rows
+    }
+
+    /// Per-entity form of [`hidden_entities`](Self::hidden_entities), for
+    /// index lookups that never build the full set.
+    fn is_hidden(&self, entity: Entity) -> bool {
+        self.is_soft_deleted(entity) || (!self.include_deceased && self.is_deceased(entity))
+    }
+
+    /// Entities hidden by the configurable visibility settings alone,
+    /// ignoring soft deletion.
+    fn hidden_by_settings(&self) -> BitSet {
+        let mut hidden = BitSet::new();
+        if !self.include_deceased {
+            self.add_deceased_to(&mut hidden);
+        }
+        hidden
+    }
+
+    // ========================================================================
+    // Read Operations (Query)
+    // These methods demonstrate the "Gateway" pattern and lazy loading concept
+    // ========================================================================
+
+    /// Retrieves every visible pet at the base level of the hierarchy.
+    ///
+    /// Reads only the `Pet` table and the leaf masks, so it returns every
+    /// entity with a `PetComponent`, including leaf types that have no
+    /// domain type yet.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT p.*, CASE WHEN d.id IS NOT NULL THEN 'Dog'
+    ///                  WHEN c.id IS NOT NULL THEN 'Cat'
+    ///                  WHEN t.id IS NOT NULL THEN 'Turtle'
+    ///                  WHEN s.id IS NOT NULL THEN 'Snake' END AS species
+    /// FROM Pet p
+    /// LEFT JOIN Dog d ON d.pet_id = p.id
+    /// LEFT JOIN Cat c ON c.pet_id = p.id
+    /// LEFT JOIN Turtle t ON t.pet_id = p.id
+    /// LEFT JOIN Snake s ON s.pet_id = p.id;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::Species;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let pets = ps.get_all_pets();
+    ///
+    /// assert_eq!(pets.len(), 5);
+    /// let cats = pets.iter().filter(|p| p.species == Some(Species::Cat)).count();
+    /// assert_eq!(cats, 2);
+    /// ```
+    pub fn get_all_pets(&self) -> Vec<PetRecord> {
+        let entities = self.ecs.entities();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+        let species_of = self.species_classifier();
+
+        let mut rows: Vec<_> = (
+            &entities,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .collect();
+        rows.sort_by_key(|(_, _, sequence, _)| sequence.map(|s| s.seq));
+
+        rows.into_iter()
+            .map(|(entity, pet, _, _)| PetRecord {
+                pet: pet.into(),
+                species: species_of(entity),
+            })
+            .collect()
+    }
+
+    /// Fetches the leaf storages once and returns a function telling which
+    /// [`Species`] an entity belongs to, if any.
+    fn species_classifier(&self) -> impl Fn(Entity) -> Option<Species> + '_ {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_hamsters = self.ecs.read_storage::<HamsterComponent>();
+        let fetched_turtles = self.ecs.read_storage::<TurtleComponent>();
+        let fetched_snakes = self.ecs.read_storage::<SnakeComponent>();
+        let fetched_goldfish = self.ecs.read_storage::<GoldfishComponent>();
+        let fetched_bettas = self.ecs.read_storage::<BettaComponent>();
+
+        move |entity| {
+            let id = entity.id();
+            if fetched_dogs.mask().contains(id) {
+                Some(Species::Dog)
+            } else if fetched_cats.mask().contains(id) {
+                Some(Species::Cat)
+            } else if fetched_hamsters.mask().contains(id) {
+                Some(Species::Hamster)
+            } else if fetched_turtles.mask().contains(id) {
+                Some(Species::Turtle)
+            } else if fetched_snakes.mask().contains(id) {
+                Some(Species::Snake)
+            } else if fetched_goldfish.mask().contains(id) {
+                Some(Species::Goldfish)
+            } else if fetched_bettas.mask().contains(id) {
+                Some(Species::Betta)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Retrieves all dogs from the ECS world.
+    ///
+    /// Performs a join query across Pet, Mammal, and Dog components,
+    /// simulating a SQL JOIN across three tables in Class Table Inheritance.
+    ///
+    /// Dogs are returned in creation order. The same guarantee holds for
+    /// every `get_all_*` query and the filtered queries built on them; see
+    /// [`sorted_by_insertion`](Self::sorted_by_insertion).
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet
+    /// INNER JOIN Mammal ON Pet.id = Mammal.pet_id
+    /// INNER JOIN Dog ON Mammal.id = Dog.mammal_id
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Dog::create(ps, "Buddy", "brown", "mutt", true, 8.0, 10);
+    /// let (ps, _) = Dog::create(ps, "Max", "black", "lab", true, 12.0, 20);
+    ///
+    /// let dogs = ps.get_all_dogs();
+    /// assert_eq!(dogs.len(), 2);
+    /// ```
+    pub fn get_all_dogs(&self) -> Vec<Dog> {
+        let mut dogs = Vec::new();
+        self.collect_dogs_into(&mut dogs);
+        dogs
+    }
+
+    /// Retrieves all cats from the ECS world.
+    ///
+    /// Similar to [`get_all_dogs`](Self::get_all_dogs), performs a join query
+    /// across Pet, Mammal, and Cat components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Cat::create(ps, "Whiskers", "orange", "tabby", true, false, true);
+    /// let (ps, _) = Cat::create(ps, "Shadow", "black", "bombay", true, true, false);
+    ///
+    /// let cats = ps.get_all_cats();
+    /// assert_eq!(cats.len(), 2);
+    /// ```
+    pub fn get_all_cats(&self) -> Vec<Cat> {
+        let mut cats = Vec::new();
+        self.collect_cats_into(&mut cats);
+        cats
+    }
+
+    /// Retrieves all hamsters from the ECS world.
+    ///
+    /// Hamster counterpart of [`get_all_cats`](Self::get_all_cats).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Hamster::create(ps, "Nibbles", "golden", "syrian", true, false, true);
+    /// let (ps, _) = Cat::create(ps, "Tom", "gray", "tabby", true, false, true);
+    ///
+    /// assert_eq!(ps.get_all_hamsters().len(), 1);
+    /// ```
+    pub fn get_all_hamsters(&self) -> Vec<Hamster> {
+        let mut hamsters = Vec::new();
+        self.extend_hamsters_matching(&mut hamsters, |_, _| true);
+        hamsters
+    }
+
+    /// Gets dwarf hamsters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// ps.create_hamster("Nibbles", "golden", "syrian", true, false, true);
+    /// ps.create_hamster("Pip", "white", "roborovski", true, true, false);
+    ///
+    /// let dwarfs = ps.get_dwarf_hamsters();
+    /// assert_eq!(dwarfs.len(), 1);
+    /// assert_eq!(dwarfs[0].pet.name, "Pip");
+    /// ```
+    pub fn get_dwarf_hamsters(&self) -> Vec<Hamster> {
+        let mut hamsters = Vec::new();
+        self.extend_hamsters_matching(&mut hamsters, |_, hamster| hamster.is_dwarf);
+        hamsters
+    }
+
+    /// Retrieves all turtles from the ECS world.
+    ///
+    /// Joins Pet, Reptile, and Turtle components, the reptile branch's
+    /// equivalent of [`get_all_dogs`](Self::get_all_dogs).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Turtle::create(ps, "Shelly", "olive", false, true, false);
+    /// let (ps, _) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// let (ps, _) = Turtle::create(ps, "Crush", "green", false, true, true);
+    ///
+    /// let names: Vec<String> = ps.get_all_turtles().into_iter().map(|t| t.pet.name).collect();
+    /// assert_eq!(names, ["Shelly", "Crush"]);
+    /// ```
+    pub fn get_all_turtles(&self) -> Vec<Turtle> {
+        let fetched_turtles = self.ecs.read_storage::<TurtleComponent>();
+        let fetched_reptiles = self.ecs.read_storage::<ReptileComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_turtles,
+            &fetched_reptiles,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .collect();
+        rows.sort_by_key(|(_, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        rows.into_iter()
+            .map(|(turtle, reptile, pet, _, _)| Turtle {
+                pet: pet.into(),
+                reptile: reptile.into(),
+                turtle_specific: turtle.into(),
+            })
+            .collect()
+    }
+
+    /// Retrieves all snakes from the ECS world.
+    ///
+    /// Snake counterpart of [`get_all_turtles`](Self::get_all_turtles).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Snake::create(ps, "Kaa", "yellow", false, 3.5);
+    /// let (ps, _) = Turtle::create(ps, "Shelly", "olive", false, true, false);
+    ///
+    /// assert_eq!(ps.get_all_snakes().len(), 1);
+    /// ```
+    pub fn get_all_snakes(&self) -> Vec<Snake> {
+        let mut snakes = Vec::new();
+        self.extend_snakes_matching(&mut snakes, |_, _| true);
+        snakes
+    }
+
+    /// Retrieves all reptiles as a polymorphic collection.
+    ///
+    /// Reptile counterpart of [`get_all_mammals`](Self::get_all_mammals):
+    /// turtles and snakes are returned interleaved, in creation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Snake::create(ps, "Kaa", "yellow", false, 3.5);
+    /// let (ps, _) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// let (ps, _) = Turtle::create(ps, "Shelly", "olive", false, true, false);
+    ///
+    /// let reptiles = ps.get_all_reptiles();
+    /// assert_eq!(reptiles.len(), 2);
+    /// assert!(reptiles[0].is_snake());
+    /// assert!(reptiles[1].is_turtle());
+    /// ```
+    pub fn get_all_reptiles(&self) -> Vec<Reptile> {
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_reptiles = self.ecs.read_storage::<ReptileComponent>();
+        let fetched_turtles = self.ecs.read_storage::<TurtleComponent>();
+        let fetched_snakes = self.ecs.read_storage::<SnakeComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_pets,
+            &fetched_reptiles,
+            fetched_turtles.maybe(),
+            fetched_snakes.maybe(),
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .collect();
+        rows.sort_by_key(|(_, _, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        rows.into_iter()
+            .filter_map(
+                |(pet, reptile, turtle, snake, _, _)| match (turtle, snake) {
+                    (Some(turtle), _) => Some(Reptile::Turtle(Turtle {
+                        pet: pet.into(),
+                        reptile: reptile.into(),
+                        turtle_specific: turtle.into(),
+                    })),
+                    (_, Some(snake)) => Some(Reptile::Snake(Snake {
+                        pet: pet.into(),
+                        reptile: reptile.into(),
+                        snake_specific: snake.into(),
+                    })),
+                    _ => None,
+                },
+            )
+            .collect()
+    }
+
+    /// Retrieves all mammals as a polymorphic collection.
+    ///
+    /// This demonstrates the solution to the `Vec<Mammal>` type safety concern
+    /// by using enum variants for type-safe polymorphism.
+    ///
+    /// Mammals are returned in creation order, all leaf types interleaved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Dog::create(ps, "Buddy", "brown", "retriever", true, 11.0, 15);
+    /// let (ps, _) = Cat::create(ps, "Mittens", "white", "persian", true, false, true);
+    /// let (ps, _) = Dog::create(ps, "Max", "black", "poodle", true, 8.0, 20);
+    ///
+    /// let mammals = ps.get_all_mammals();
+    /// assert_eq!(mammals.len(), 3);
+    ///
+    /// // All mammals share common interface
+    /// for mammal in mammals {
+    ///     println!("{} says {}", mammal.name(), mammal.make_sound());
+    /// }
+    /// ```
+    pub fn get_all_mammals(&self) -> Vec<Mammal> {
+        let mut mammals = Vec::new();
+        self.collect_mammals_into(&mut mammals);
+        mammals
+    }
+
+    /// Retrieves all mammals ordered by `key` in the given direction.
+    ///
+    /// Creation order reflects history rather than content, so callers that
+    /// need a presentation order should ask for one here. Ties on name or
+    /// hair color are broken by UUID, making the result fully deterministic.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet p JOIN Mammal m ON m.pet_id = p.id
+    /// ORDER BY p.name DESC, p.id DESC;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::{Order, SortKey};
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let names: Vec<_> = ps
+    ///     .get_all_mammals_sorted(SortKey::Name, Order::Ascending)
+    ///     .iter()
+    ///     .map(|m| m.name().to_string())
+    ///     .collect();
+    /// assert_eq!(names, ["Berlioz", "Shippen", "Simba", "Sophie", "Waterloo"]);
+    ///
+    /// let by_color = ps.get_all_mammals_sorted(SortKey::HairColor, Order::Descending);
+    /// assert_eq!(by_color[0].hair_color(), "gray");
+    /// ```
+    pub fn get_all_mammals_sorted(&self, key: SortKey, order: Order) -> Vec<Mammal> {
+        self.get_all_mammals_sorted_by(|a, b| {
+            let ordering = match key {
+                SortKey::Name => a.name().cmp(b.name()),
+                SortKey::HairColor => a.hair_color().cmp(b.hair_color()),
+                SortKey::Uuid => Ordering::Equal,
+            }
+            .then_with(|| a.uuid().cmp(b.uuid()));
+
+            match order {
+                Order::Ascending => ordering,
+                Order::Descending => ordering.reverse(),
+            }
+        })
+    }
+
+    /// Retrieves all mammals ordered by an arbitrary comparator.
+    ///
+    /// The sort is stable, so mammals the comparator considers equal keep
+    /// their creation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let by_name_len = ps.get_all_mammals_sorted_by(|a, b| a.name().len().cmp(&b.name().len()));
+    /// assert_eq!(by_name_len[0].name(), "Simba");
+    /// assert_eq!(by_name_len[4].name(), "Waterloo");
+    /// ```
+    pub fn get_all_mammals_sorted_by(
+        &self,
+        compare: impl FnMut(&Mammal, &Mammal) -> Ordering,
+    ) -> Vec<Mammal> {
+        let mut mammals = self.get_all_mammals();
+        mammals.sort_by(compare);
+        mammals
+    }
+
+    /// Retrieves all mammals in creation order, each with its insertion
+    /// sequence number.
+    ///
+    /// Every pet gets the next number of a per-state counter when it is
+    /// created or [`insert`](Self::insert)ed. Unlike join order, this is not
+    /// affected by the ECS reusing the slots of deleted entities, and
+    /// reclassifying a pet keeps its number. The `get_all_*` queries return
+    /// results in this same order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let tom = ps.create_cat("Tom", "gray", None, true, false, true);
+    /// ps.create_dog("Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// // Felix reuses Tom's entity slot but still sorts last
+    /// ps.delete(&tom);
+    /// ps.create_cat("Felix", "black", None, true, false, false);
+    ///
+    /// let rows: Vec<_> = ps
+    ///     .sorted_by_insertion()
+    ///     .into_iter()
+    ///     .map(|(seq, mammal)| (seq, mammal.name().to_string()))
+    ///     .collect();
+    /// assert_eq!(rows, [(1, "Rex".to_string()), (2, "Felix".to_string())]);
+    /// ```
+    pub fn sorted_by_insertion(&self) -> Vec<(u64, Mammal)> {
+        self.mammal_rows_by_insertion(|_, _| true)
+    }
+
+    /// Lazily iterates over all visible dogs.
+    ///
+    /// Unlike [`get_all_dogs`](Self::get_all_dogs), dogs are composed one at
+    /// a time as the iterator is advanced, so `take(5)` or `find(...)` only
+    /// pay for the dogs they actually look at. Dogs come in creation order;
+    /// only their row indices are sorted up front. The iterator holds read
+    /// access to the world until it is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state_with(1_000, 0);
+    /// let first_five: Vec<_> = ps.iter_dogs().take(5).collect();
+    /// assert_eq!(first_five.len(), 5);
+    ///
+    /// let dog = ps.iter_dogs().find(|dog| dog.pet.name == "Dog 42");
+    /// assert!(dog.is_some());
+    /// ```
+    pub fn iter_dogs(&self) -> impl Iterator<Item = Dog> + '_ {
+        let entities = self.ecs.entities();
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let rows = self.rows_by_insertion(&self.visible_rows(&[
+            fetched_dogs.mask(),
+            fetched_mammals.mask(),
+            fetched_pets.mask(),
+        ]));
+
+        rows.into_iter().filter_map(move |id| {
+            let entity = entities.entity(id);
+            Some(Dog {
+                pet: fetched_pets.get(entity)?.into(),
+                mammal: fetched_mammals.get(entity)?.into(),
+                dog_specific: fetched_dogs.get(entity)?.into(),
+            })
+        })
+    }
+
+    /// Lazily iterates over all visible cats.
+    ///
+    /// Cat counterpart of [`iter_dogs`](Self::iter_dogs).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert!(ps.iter_cats().any(|cat| cat.cat_specific.sits_on_keyboard));
+    /// ```
+    pub fn iter_cats(&self) -> impl Iterator<Item = Cat> + '_ {
+        let entities = self.ecs.entities();
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let rows = self.rows_by_insertion(&self.visible_rows(&[
+            fetched_cats.mask(),
+            fetched_mammals.mask(),
+            fetched_pets.mask(),
+        ]));
+
+        rows.into_iter().filter_map(move |id| {
+            let entity = entities.entity(id);
+            Some(Cat {
+                pet: fetched_pets.get(entity)?.into(),
+                mammal: fetched_mammals.get(entity)?.into(),
+                cat_specific: fetched_cats.get(entity)?.into(),
+            })
+        })
+    }
+
+    /// Lazily iterates over all visible hamsters.
+    ///
+    /// Hamster counterpart of [`iter_dogs`](Self::iter_dogs).
+    pub fn iter_hamsters(&self) -> impl Iterator<Item = Hamster> + '_ {
+        let entities = self.ecs.entities();
+        let fetched_hamsters = self.ecs.read_storage::<HamsterComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let rows = self.rows_by_insertion(&self.visible_rows(&[
+            fetched_hamsters.mask(),
+            fetched_mammals.mask(),
+            fetched_pets.mask(),
+        ]));
+
+        rows.into_iter().filter_map(move |id| {
+            let entity = entities.entity(id);
+            Some(Hamster {
+                pet: fetched_pets.get(entity)?.into(),
+                mammal: fetched_mammals.get(entity)?.into(),
+                hamster_specific: fetched_hamsters.get(entity)?.into(),
+            })
+        })
+    }
+
+    /// Lazily iterates over all visible mammals, all leaf types interleaved
+    /// in creation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let first_cat = ps.iter_mammals().find(|mammal| mammal.make_sound() == "Meow!");
+    /// assert_eq!(first_cat.unwrap().name(), "Berlioz");
+    /// ```
+    pub fn iter_mammals(&self) -> impl Iterator<Item = Mammal> + '_ {
+        let entities = self.ecs.entities();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let rows = self
+            .rows_by_insertion(&self.visible_rows(&[fetched_mammals.mask(), fetched_pets.mask()]));
+
+        rows.into_iter()
+            .filter_map(move |id| self.mammal_at(entities.entity(id)))
+    }
+
+    /// Gets mammals filtered by hair color.
+    ///
+    /// Demonstrates how business logic can be implemented at the gateway layer.
+    ///
+    /// Hair colors are stored trimmed and lowercased, and the query is
+    /// normalized the same way, so `"Golden "` finds `"golden"`. Use
+    /// [`get_mammals_by_hair_color_exact`](Self::get_mammals_by_hair_color_exact)
+    /// to compare verbatim.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, _) = Dog::create(ps, "Goldie", "golden", "retriever", true, 12.0, 15);
+    /// let (ps, _) = Cat::create(ps, "Shadow", "black", "bombay", true, false, true);
+    /// let (ps, _) = Dog::create(ps, "Gold", "Golden", "labrador", true, 10.0, 18);
+    ///
+    /// let golden_mammals = ps.get_mammals_by_hair_color("golden");
+    /// assert_eq!(golden_mammals.len(), 2);
+    /// assert_eq!(ps.get_mammals_by_hair_color(" GOLDEN").len(), 2);
+    /// ```
+    pub fn get_mammals_by_hair_color(&self, hair_color: &str) -> Vec<Mammal> {
+        let mut mammals = Vec::new();
+        self.collect_mammals_by_hair_color_into(hair_color, &mut mammals);
+        mammals
+    }
+
+    /// Gets mammals whose stored hair color equals `hair_color` verbatim.
+    ///
+    /// Escape hatch from the query normalization of
+    /// [`get_mammals_by_hair_color`](Self::get_mammals_by_hair_color). Stored
+    /// values are always normalized, so only lowercase, trimmed queries can
+    /// match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.get_mammals_by_hair_color_exact("blond").len(), 3);
+    /// assert!(ps.get_mammals_by_hair_color_exact("Blond").is_empty());
+    /// ```
+    pub fn get_mammals_by_hair_color_exact(&self, hair_color: &str) -> Vec<Mammal> {
+        let mut mammals = Vec::new();
+        self.extend_mammals_matching(&mut mammals, |_, mammal| mammal.hair_color == hair_color);
+        mammals
+    }
+
+    /// Gets dogs of exactly the given breed.
+    ///
+    /// The breed is compared inside the join, so non-matching dogs are never
+    /// composed. Dogs with a `NULL` breed never match.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet p
+    /// JOIN Mammal m ON m.pet_id = p.id
+    /// JOIN Dog d ON d.mammal_id = m.id
+    /// WHERE m.breed = 'schnauzer';
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let schnauzers = ps.get_dogs_by_breed("schnauzer");
+    /// assert_eq!(schnauzers.len(), 2);
+    /// assert!(ps.get_dogs_by_breed("shorthair").is_empty());
+    /// ```
+    pub fn get_dogs_by_breed(&self, breed: &str) -> Vec<Dog> {
+        let mut dogs = Vec::new();
+        self.extend_dogs_matching(&mut dogs, |mammal, _| {
+            mammal.breed.as_deref() == Some(breed)
+        });
+        dogs
+    }
+
+    /// Gets mammals whose hair color is any of `hair_colors`.
+    ///
+    /// Each query value is normalized once, like
+    /// [`get_mammals_by_hair_color`](Self::get_mammals_by_hair_color), and
+    /// rows are tested against the whole list in a single pass. An empty
+    /// list matches nothing, as `IN ()` would.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet p JOIN Mammal m ON m.pet_id = p.id
+    /// WHERE m.hair_color IN ('blond', 'gray');
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.get_mammals_by_hair_colors(&["blond", "Gray"]).len(), 4);
+    /// assert!(ps.get_mammals_by_hair_colors(&[]).is_empty());
+    /// ```
+    pub fn get_mammals_by_hair_colors(&self, hair_colors: &[&str]) -> Vec<Mammal> {
+        let hair_colors: Vec<String> = hair_colors
+            .iter()
+            .map(|hair_color| normalize_hair_color(hair_color))
+            .collect();
+
+        let mut mammals = Vec::new();
+        self.extend_mammals_matching(&mut mammals, |_, mammal| {
+            hair_colors.contains(&mammal.hair_color)
+        });
+        mammals
+    }
+
+    /// Gets mammals whose breed is any of `breeds`, compared exactly.
+    ///
+    /// Mammals with a `NULL` breed never match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.get_mammals_by_breeds(&["labrador", "shorthair"]).len(), 3);
+    /// ```
+    pub fn get_mammals_by_breeds(&self, breeds: &[&str]) -> Vec<Mammal> {
+        let mut mammals = Vec::new();
+        self.extend_mammals_matching(&mut mammals, |_, mammal| {
+            mammal
+                .breed
+                .as_deref()
+                .is_some_and(|breed| breeds.contains(&breed))
+        });
+        mammals
+    }
+
+    /// Gets dogs whose breed is any of `breeds`, compared exactly.
+    ///
+    /// Multi-value form of [`get_dogs_by_breed`](Self::get_dogs_by_breed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.get_dogs_by_breeds(&["schnauzer", "labrador"]).len(), 3);
+    /// assert!(ps.get_dogs_by_breeds(&["shorthair"]).is_empty());
+    /// ```
+    pub fn get_dogs_by_breeds(&self, breeds: &[&str]) -> Vec<Dog> {
+        let mut dogs = Vec::new();
+        self.extend_dogs_matching(&mut dogs, |mammal, _| {
+            mammal
+                .breed
+                .as_deref()
+                .is_some_and(|breed| breeds.contains(&breed))
+        });
+        dogs
+    }
+
+    /// Gets dogs that know at least `n` commands.
+    ///
+    /// A predicate on a leaf-table column, evaluated inside the join.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet p
+    /// JOIN Mammal m ON m.pet_id = p.id
+    /// JOIN Dog d ON d.mammal_id = m.id
+    /// WHERE d.num_commands_known >= n;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.get_dogs_knowing_at_least(42).len(), 2);
+    /// assert_eq!(ps.get_dogs_knowing_at_least(0).len(), 3);
+    /// ```
+    pub fn get_dogs_knowing_at_least(&self, n: i32) -> Vec<Dog> {
+        let mut dogs = Vec::new();
+        self.extend_dogs_matching(&mut dogs, |_, dog| dog.num_commands_known >= n);
+        dogs
+    }
+
+    /// Gets dogs whose tail is strictly longer than `len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let long_tails = ps.get_dogs_with_tail_longer_than(2.0);
+    /// assert_eq!(long_tails.len(), 1);
+    /// assert_eq!(long_tails[0].pet.name, "Waterloo");
+    /// ```
+    pub fn get_dogs_with_tail_longer_than(&self, len: f64) -> Vec<Dog> {
+        let mut dogs = Vec::new();
+        self.extend_dogs_matching(&mut dogs, |_, dog| dog.tail_length > len);
+        dogs
+    }
+
+    /// Gets dogs whose tail length falls within `range`.
+    ///
+    /// Accepts any range form, so slider-style filters can pass `2.0..=12.0`
+    /// or an open-ended `5.0..`. Evaluated inside the join.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Dog d ... WHERE d.tail_length BETWEEN 2.0 AND 12.0;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.get_dogs_with_tail_length_in(2.0..=12.0).len(), 3);
+    /// assert_eq!(ps.get_dogs_with_tail_length_in(2.5..).len(), 1);
+    /// ```
+    pub fn get_dogs_with_tail_length_in(&self, range: impl RangeBounds<f64>) -> Vec<Dog> {
+        let mut dogs = Vec::new();
+        self.extend_dogs_matching(&mut dogs, |_, dog| range.contains(&dog.tail_length));
+        dogs
+    }
+
+    /// Gets snakes whose length falls within `range`.
+    ///
+    /// Snake counterpart of
+    /// [`get_dogs_with_tail_length_in`](Self::get_dogs_with_tail_length_in).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// ps.create_snake("Kaa", "yellow", false, 3.5);
+    /// ps.create_snake("Noodle", "orange", false, 1.2);
+    ///
+    /// assert_eq!(ps.get_snakes_with_length_in(2.0..).len(), 1);
+    /// assert_eq!(ps.get_snakes_with_length_in(..=5.0).len(), 2);
+    /// ```
+    pub fn get_snakes_with_length_in(&self, range: impl RangeBounds<f64>) -> Vec<Snake> {
+        let mut snakes = Vec::new();
+        self.extend_snakes_matching(&mut snakes, |_, snake| range.contains(&snake.length));
+        snakes
+    }
+
+    /// Gets snakes marked as poisonous in the Reptile table.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Snake s ... WHERE r.is_poisonous = true;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// ps.create_snake("Kaa", "yellow", false, 3.5);
+    /// ps.create_snake("Nagini", "green", true, 6.0);
+    ///
+    /// let poisonous = ps.get_poisonous_snakes();
+    /// assert_eq!(poisonous.len(), 1);
+    /// assert_eq!(poisonous[0].pet.name, "Nagini");
+    /// ```
+    pub fn get_poisonous_snakes(&self) -> Vec<Snake> {
+        let mut snakes = Vec::new();
+        self.extend_snakes_matching(&mut snakes, |reptile, _| reptile.is_poisonous);
+        snakes
+    }
+
+    /// Gets dogs whose number of known commands falls within `range`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.get_dogs_with_commands_in(40..=50).len(), 1);
+    /// assert_eq!(ps.get_dogs_with_commands_in(..10).len(), 1);
+    /// ```
+    pub fn get_dogs_with_commands_in(&self, range: impl RangeBounds<i32>) -> Vec<Dog> {
+        let mut dogs = Vec::new();
+        self.extend_dogs_matching(&mut dogs, |_, dog| range.contains(&dog.num_commands_known));
+        dogs
+    }
+
+    /// Gets cats that sit on keyboards.
+    ///
+    /// Boolean leaf-table filter evaluated inside the join.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet p
+    /// JOIN Mammal m ON m.pet_id = p.id
+    /// JOIN Cat c ON c.mammal_id = m.id
+    /// WHERE c.sits_on_keyboard;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let culprits = ps.get_keyboard_sitting_cats();
+    /// assert_eq!(culprits.len(), 1);
+    /// assert_eq!(culprits[0].pet.name, "Simba");
+    /// ```
+    pub fn get_keyboard_sitting_cats(&self) -> Vec<Cat> {
+        let mut cats = Vec::new();
+        self.extend_cats_matching(&mut cats, |_, cat| cat.sits_on_keyboard);
+        cats
+    }
+
+    /// Gets declawed cats.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.get_declawed_cats().len(), 2);
+    /// ```
+    pub fn get_declawed_cats(&self) -> Vec<Cat> {
+        let mut cats = Vec::new();
+        self.extend_cats_matching(&mut cats, |_, cat| cat.declawed);
+        cats
+    }
+
+    /// Retrieves all mammals with exactly the given name.
+    ///
+    /// Names are the natural key users usually have, but unlike UUIDs they
+    /// are not unique (unless [`unique_names`](PetStateOptions::unique_names)
+    /// is enabled), hence the `Vec`.
+    ///
+    /// Served from the name index: the cost depends on the number of
+    /// namesakes, not on the number of pets.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet p
+    /// JOIN Mammal m ON m.pet_id = p.id
+    /// WHERE p.name = 'Simba';
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let simbas = ps.get_pets_by_name("Simba");
+    /// assert_eq!(simbas.len(), 1);
+    /// assert!(simbas[0].is_cat());
+    /// assert!(ps.get_pets_by_name("simba").is_empty());
+    /// ```
+    pub fn get_pets_by_name(&self, name: &str) -> Vec<Mammal> {
+        let Some(entities) = self.by_name.get(name) else {
+            return Vec::new();
+        };
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+
+        let mut entities: Vec<Entity> = entities
+            .iter()
+            .copied()
+            .filter(|&entity| !self.is_hidden(entity))
+            .collect();
+        entities.sort_by_key(|&entity| fetched_sequences.get(entity).map(|s| s.seq));
+        entities
+            .into_iter()
+            .filter_map(|entity| self.mammal_at(entity))
+            .collect()
+    }
+
+    /// Returns the first visible mammal satisfying `predicate`.
+    ///
+    /// Walks [`iter_mammals`](Self::iter_mammals), composing one mammal at a
+    /// time and stopping at the first match, unlike
+    /// `get_all_mammals().into_iter().find(...)`, which composes everything
+    /// first. Mammals are tried in creation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state_with(10_000, 10);
+    ///
+    /// // Only composes dogs until the first long-tailed one
+    /// let dog = ps.find_first(|mammal| mammal.as_dog().is_some_and(|d| d.dog_specific.tail_length > 2.0));
+    /// assert!(dog.is_some());
+    ///
+    /// assert!(ps.find_first(|mammal| mammal.name() == "Nobody").is_none());
+    /// ```
+    pub fn find_first(&self, predicate: impl Fn(&Mammal) -> bool) -> Option<Mammal> {
+        self.iter_mammals().find(|mammal| predicate(mammal))
+    }
+
+    /// Retrieves the first mammal with exactly the given name.
+    ///
+    /// Resolves through the name index and composes only the earliest
+    /// created visible namesake, so the result is always
+    /// `get_pets_by_name(name)[0]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let sophie = ps.find_first_by_name("Sophie").unwrap();
+    /// assert_eq!(sophie.hair_color(), "blond");
+    /// assert!(ps.find_first_by_name("Garfield").is_none());
+    ///
+    /// // Earliest namesake wins, whatever its type
+    /// let mut ps = PetState::new();
+    /// ps.create_cat("Max", "black", None, true, false, true);
+    /// ps.create_dog("Max", "brown", None, true, 10.0, 3);
+    /// let max = ps.find_first_by_name("Max").unwrap();
+    /// assert!(max.is_cat());
+    /// assert_eq!(Some(max), ps.get_pets_by_name("Max").into_iter().next());
+    /// ```
+    pub fn find_first_by_name(&self, name: &str) -> Option<Mammal> {
+        let entities = self.by_name.get(name)?;
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+
+        let first = entities
+            .iter()
+            .copied()
+            .filter(|&entity| fetched_mammals.contains(entity) && !self.is_hidden(entity))
+            .min_by_key(|&entity| fetched_sequences.get(entity).map(|s| s.seq))?;
+        self.mammal_at(first)
+    }
+
+    /// Case-insensitive name search for autocomplete, best matches first.
+    ///
+    /// A pet matches if its name contains `query`, ignoring case. Results
+    /// are ranked by match quality and then alphabetically:
+    ///
+    /// 1. whole-name matches (`"simba"` → "Simba")
+    /// 2. prefix matches (`"shi"` → "Shippen")
+    /// 3. other substring matches (`"pp"` → "Shippen")
+    ///
+    /// An empty (or all-whitespace) query matches nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// for name in ["Bo", "Bobby", "Jimbo", "Rex"] {
+    ///     ps.create_dog(name, "brown", None, true, 10.0, 3);
+    /// }
+    ///
+    /// let names: Vec<String> = ps
+    ///     .search_by_name("bo")
+    ///     .iter()
+    ///     .map(|m| m.name().to_string())
+    ///     .collect();
+    /// assert_eq!(names, ["Bo", "Bobby", "Jimbo"]);
+    /// ```
+    pub fn search_by_name(&self, query: &str) -> Vec<Mammal> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut mammals = Vec::new();
+        self.extend_mammals_matching(&mut mammals, |pet, _| {
+            pet.name.to_lowercase().contains(&query)
+        });
+        mammals.sort_by_cached_key(|mammal| {
+            let name = mammal.name().to_lowercase();
+            let rank = if name == query {
+                0
+            } else if name.starts_with(&query) {
+                1
+            } else {
+                2
+            };
+            (rank, name)
+        });
+        mammals
+    }
+
+    /// Gets the visible dogs satisfying an arbitrary predicate.
+    ///
+    /// The predicate runs inside the join: each row is composed once to be
+    /// tested and rejected dogs are dropped immediately, so no intermediate
+    /// collection of every dog is built. For criteria on single columns,
+    /// [`find`](Self::find) avoids composing rejected rows at all.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let clever = ps.query_dogs(|dog| dog.dog_specific.num_commands_known > 10);
+    /// assert_eq!(clever.len(), 2);
+    /// ```
+    pub fn query_dogs(&self, predicate: impl Fn(&Dog) -> bool) -> Vec<Dog> {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut matches: Vec<_> = (
+            &fetched_dogs,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .map(|(dog, mammal, pet, sequence, _)| {
+                let dog = Dog {
+                    pet: pet.into(),
+                    mammal: mammal.into(),
+                    dog_specific: dog.into(),
+                };
+                (sequence.map(|s| s.seq), dog)
+            })
+            .filter(|(_, dog)| predicate(dog))
+            .collect();
+        matches.sort_by_key(|(seq, _)| *seq);
+        matches.into_iter().map(|(_, dog)| dog).collect()
+    }
+
+    /// Gets the visible cats satisfying an arbitrary predicate.
+    ///
+    /// Cat counterpart of [`query_dogs`](Self::query_dogs).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let keyboard_cats = ps.query_cats(|cat| cat.cat_specific.sits_on_keyboard);
+    /// assert_eq!(keyboard_cats[0].pet.name, "Simba");
+    /// ```
+    pub fn query_cats(&self, predicate: impl Fn(&Cat) -> bool) -> Vec<Cat> {
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut matches: Vec<_> = (
+            &fetched_cats,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .map(|(cat, mammal, pet, sequence, _)| {
+                let cat = Cat {
+                    pet: pet.into(),
+                    mammal: mammal.into(),
+                    cat_specific: cat.into(),
+                };
+                (sequence.map(|s| s.seq), cat)
+            })
+            .filter(|(_, cat)| predicate(cat))
+            .collect();
+        matches.sort_by_key(|(seq, _)| *seq);
+        matches.into_iter().map(|(_, cat)| cat).collect()
+    }
+
+    /// Gets the visible mammals satisfying an arbitrary predicate.
+    ///
+    /// Mammals come in creation order, all leaf types interleaved. Like
+    /// [`query_dogs`](Self::query_dogs), rows are tested as they are
+    /// composed rather than after collecting everything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let short_names = ps.query_mammals(|mammal| mammal.name().len() <= 5);
+    /// assert_eq!(short_names.len(), 1);
+    /// assert_eq!(short_names[0].name(), "Simba");
+    /// ```
+    pub fn query_mammals(&self, predicate: impl Fn(&Mammal) -> bool) -> Vec<Mammal> {
+        let mut matches = Vec::new();
+        self.visit_mammal_rows(|_, _, leaf, mammal, pet| {
+            let mammal = leaf.compose(mammal, pet);
+            if predicate(&mammal) {
+                matches.push(mammal);
+            }
+        });
+        matches
+    }
+
+    /// Returns `true` if a pet with the given UUID is stored.
+    ///
+    /// Scans only the Pet table and composes nothing. Like the other UUID
+    /// lookups it ignores visibility settings: hidden pets still exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let rex = ps.create_dog("Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// assert!(ps.exists(&rex));
+    /// assert!(ps.contains_name("Rex"));
+    /// ps.delete(&rex);
+    /// assert!(!ps.exists(&rex));
+    /// assert!(!ps.contains_name("Rex"));
+    /// ```
+    pub fn exists(&self, uuid: &str) -> bool {
+        self.find_entity(uuid).is_some()
+    }
+
+    /// Returns `true` if any stored pet has exactly the given name.
+    ///
+    /// Cheap counterpart of [`find_first_by_name`](Self::find_first_by_name);
+    /// ignores visibility settings like [`exists`](Self::exists).
+    pub fn contains_name(&self, name: &str) -> bool {
+        self.by_name.contains_key(name)
+    }
+
+    /// Returns one page of visible dogs, skipping `offset` and taking up to `limit`.
+    ///
+    /// # Ordering
+    ///
+    /// Pages follow creation order, like [`get_all_dogs`](Self::get_all_dogs).
+    /// The order is stable while no pets are created or deleted, so pages
+    /// fetched in between fit together without gaps or repeats. Rows are
+    /// sorted by reference and only the returned page is composed.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet p
+    /// JOIN Mammal m ON m.pet_id = p.id
+    /// JOIN Dog d ON d.mammal_id = m.id
+    /// ORDER BY p.seq
+    /// LIMIT limit OFFSET offset;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state_with(25, 0);
+    /// let pages: Vec<usize> = (0..3).map(|page| ps.get_dogs_paged(page * 10, 10).len()).collect();
+    /// assert_eq!(pages, [10, 10, 5]);
+    /// assert_eq!(ps.get_dogs_paged(10, 1)[0].pet.name, "Dog 11");
+    /// ```
+    pub fn get_dogs_paged(&self, offset: usize, limit: usize) -> Vec<Dog> {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_dogs,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .collect();
+        rows.sort_by_key(|(_, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        rows.into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|(dog, mammal, pet, _, _)| Dog {
+                pet: pet.into(),
+                mammal: mammal.into(),
+                dog_specific: dog.into(),
+            })
+            .collect()
+    }
+
+    /// Returns one page of visible cats; see [`get_dogs_paged`](Self::get_dogs_paged).
+    pub fn get_cats_paged(&self, offset: usize, limit: usize) -> Vec<Cat> {
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_cats,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .collect();
+        rows.sort_by_key(|(_, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        rows.into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|(cat, mammal, pet, _, _)| Cat {
+                pet: pet.into(),
+                mammal: mammal.into(),
+                cat_specific: cat.into(),
+            })
+            .collect()
+    }
+
+    /// Returns one page of visible mammals.
+    ///
+    /// Pages through the same order as [`get_all_mammals`](Self::get_all_mammals):
+    /// all leaf types interleaved in creation order. See
+    /// [`get_dogs_paged`](Self::get_dogs_paged) for stability across pages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let names: Vec<String> = ps
+    ///     .get_mammals_paged(2, 2)
+    ///     .iter()
+    ///     .map(|m| m.name().to_string())
+    ///     .collect();
+    /// assert_eq!(names, ["Waterloo", "Berlioz"]);
+    /// ```
+    pub fn get_mammals_paged(&self, offset: usize, limit: usize) -> Vec<Mammal> {
+        let mut page = Vec::new();
+        let mut index = 0;
+        self.visit_mammal_rows(|_, _, leaf, mammal, pet| {
+            if index >= offset && page.len() < limit {
+                page.push(leaf.compose(mammal, pet));
+            }
+            index += 1;
+        });
+        page
+    }
+
+    /// Returns up to `limit` visible mammals whose UUID sorts after `cursor`.
+    ///
+    /// Keyset pagination: pages are ordered by UUID and each page starts
+    /// strictly after the last UUID of the previous one, so creating or
+    /// deleting pets between requests never shifts later pages the way
+    /// offsets do. Pass `None` for the first page, then the returned
+    /// [`next_cursor`](MammalPage::next_cursor) until it is `None`.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet p JOIN Mammal m ON m.pet_id = p.id
+    /// WHERE p.id > cursor
+    /// ORDER BY p.id
+    /// LIMIT limit;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state_with(4, 3);
+    /// let first = ps.get_mammals_after(None, 4);
+    /// assert_eq!(first.items.len(), 4);
+    ///
+    /// // Deleting an already-seen pet doesn't disturb the next page
+    /// ps.delete(first.items[0].uuid());
+    ///
+    /// let second = ps.get_mammals_after(first.next_cursor.as_deref(), 4);
+    /// assert_eq!(second.items.len(), 3);
+    /// assert_eq!(second.next_cursor, None);
+    /// ```
+    pub fn get_mammals_after(&self, cursor: Option<&str>, limit: usize) -> MammalPage {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_hamsters = self.ecs.read_storage::<HamsterComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        let dogs = (&fetched_dogs, &fetched_mammals, &fetched_pets, !&hidden)
+            .join()
+            .map(|(dog, mammal, pet, _)| (LeafRow::Dog(dog), mammal, pet));
+        let cats = (&fetched_cats, &fetched_mammals, &fetched_pets, !&hidden)
+            .join()
+            .map(|(cat, mammal, pet, _)| (LeafRow::Cat(cat), mammal, pet));
+        let hamsters = (&fetched_hamsters, &fetched_mammals, &fetched_pets, !&hidden)
+            .join()
+            .map(|(hamster, mammal, pet, _)| (LeafRow::Hamster(hamster), mammal, pet));
+
+        let mut rows: Vec<_> = dogs
+            .chain(cats)
+            .chain(hamsters)
+            .filter(|(_, _, pet)| cursor.is_none_or(|cursor| pet.uuid.as_str() > cursor))
+            .collect();
+        rows.sort_unstable_by(|(_, _, a), (_, _, b)| a.uuid.cmp(&b.uuid));
+
+        let has_more = rows.len() > limit;
+        rows.truncate(limit);
+        let next_cursor = has_more
+            .then(|| rows.last().map(|(_, _, pet)| pet.uuid.clone()))
+            .flatten();
+
+        MammalPage {
+            items: rows
+                .into_iter()
+                .map(|(leaf, mammal, pet)| leaf.compose(mammal, pet))
+                .collect(),
+            next_cursor,
+        }
+    }
+
+    /// Enumerates every visible pet's UUID and species.
+    ///
+    /// Reads only the Pet table plus the leaf tables' presence masks, so it
+    /// is much cheaper than [`get_all_mammals`](Self::get_all_mammals) when
+    /// the caller only needs to know which pets exist (reconciliation jobs,
+    /// progress bars, external indexes).
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM (
+    ///     SELECT p.id, CASE
+    ///         WHEN d.id IS NOT NULL THEN 'Dog'
+    ///         WHEN c.id IS NOT NULL THEN 'Cat'
+    ///         WHEN h.id IS NOT NULL THEN 'Hamster'
+    ///         WHEN t.id IS NOT NULL THEN 'Turtle'
+    ///         WHEN s.id IS NOT NULL THEN 'Snake'
+    ///         WHEN g.id IS NOT NULL THEN 'Goldfish'
+    ///         WHEN b.id IS NOT NULL THEN 'Betta'
+    ///     END AS species
+    ///     FROM Pet p
+    ///     LEFT JOIN Mammal m ON m.pet_id = p.id
+    ///     LEFT JOIN Dog d ON d.mammal_id = m.id
+    ///     LEFT JOIN Cat c ON c.mammal_id = m.id
+    ///     LEFT JOIN Hamster h ON h.mammal_id = m.id
+    ///     LEFT JOIN Reptile r ON r.pet_id = p.id
+    ///     LEFT JOIN Turtle t ON t.reptile_id = r.id
+    ///     LEFT JOIN Snake s ON s.reptile_id = r.id
+    ///     LEFT JOIN Fish f ON f.pet_id = p.id
+    ///     LEFT JOIN Goldfish g ON g.fish_id = f.id
+    ///     LEFT JOIN Betta b ON b.fish_id = f.id
+    /// ) WHERE species IS NOT NULL;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::Species;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let dogs = ps.ids().filter(|(_, species)| *species == Species::Dog).count();
+    /// assert_eq!(dogs, 3);
+    /// assert_eq!(ps.ids().count(), 5);
+    /// ```
+    pub fn ids(&self) -> impl Iterator<Item = (String, Species)> {
+        let hidden = self.hidden_entities();
+        let entities = self.ecs.entities();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let species_of = self.species_classifier();
+
+        let ids: Vec<_> = (&entities, &fetched_pets, !&hidden)
+            .join()
+            .filter_map(|(entity, pet, _)| Some((pet.uuid.clone(), species_of(entity)?)))
+            .collect();
+        ids.into_iter()
+    }
+
+    // ========================================================================
+    // Zero-Clone Visitors
+    // Hand out borrowed views of component rows for read-only bulk work
+    // ========================================================================
+
+    /// Calls `visit` with borrowed views of every visible dog.
+    ///
+    /// No strings are cloned: names, UUIDs and hair colors are read in place
+    /// from the component storages. The leaf columns are plain values and are
+    /// passed as a [`DogData`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let mut blond_commands = 0;
+    ///
+    /// ps.for_each_dog(|_pet, mammal, dog| {
+    ///     if mammal.hair_color == "blond" {
+    ///         blond_commands += dog.num_commands_known;
+    ///     }
+    /// });
+    /// assert_eq!(blond_commands, 60);
+    /// ```
+    pub fn for_each_dog(&self, mut visit: impl FnMut(PetView<'_>, MammalView<'_>, &DogData)) {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        for (dog, mammal, pet, _) in
+            (&fetched_dogs, &fetched_mammals, &fetched_pets, !&hidden).join()
+        {
+            visit(pet.into(), mammal.into(), &dog.into());
+        }
+    }
+
+    /// Calls `visit` with borrowed views of every visible cat.
+    ///
+    /// Cat counterpart of [`for_each_dog`](Self::for_each_dog).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let mut keyboard_sitter = None;
+    ///
+    /// ps.for_each_cat(|pet, _mammal, cat| {
+    ///     if cat.sits_on_keyboard {
+    ///         keyboard_sitter = Some(pet.name == "Simba");
+    ///     }
+    /// });
+    /// assert_eq!(keyboard_sitter, Some(true));
+    /// ```
+    pub fn for_each_cat(&self, mut visit: impl FnMut(PetView<'_>, MammalView<'_>, &CatData)) {
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        for (cat, mammal, pet, _) in
+            (&fetched_cats, &fetched_mammals, &fetched_pets, !&hidden).join()
+        {
+            visit(pet.into(), mammal.into(), &cat.into());
+        }
+    }
+
+    // ========================================================================
+    // Buffer-Reusing Read Operations
+    // The `get_*` queries above delegate to these; hot loops can call them
+    // directly to reuse one allocation across many queries
+    // ========================================================================
+
+    /// Clears `out` and fills it with every dog, reusing its allocation.
+    ///
+    /// Same join as [`get_all_dogs`](Self::get_all_dogs), but writes into a
+    /// caller-provided buffer so per-frame or per-request loops don't allocate
+    /// a fresh `Vec` each time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let mut dogs = Vec::new();
+    ///
+    /// for _ in 0..3 {
+    ///     ps.collect_dogs_into(&mut dogs);
+    ///     assert_eq!(dogs.len(), 3);
+    /// }
+    /// ```
+    pub fn collect_dogs_into(&self, out: &mut Vec<Dog>) {
+        out.clear();
+        self.extend_dogs_matching(out, |_, _| true);
+    }
+
+    /// Clears `out` and fills it with every cat, reusing its allocation.
+    ///
+    /// Buffer-reusing counterpart of [`get_all_cats`](Self::get_all_cats).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let mut cats = Vec::with_capacity(16);
+    ///
+    /// ps.collect_cats_into(&mut cats);
+    /// assert_eq!(cats.len(), 2);
+    /// assert!(cats.capacity() >= 16);
+    /// ```
+    pub fn collect_cats_into(&self, out: &mut Vec<Cat>) {
+        out.clear();
+        self.extend_cats_matching(out, |_, _| true);
+    }
+
+    /// Clears `out` and fills it with every mammal, in creation order.
+    ///
+    /// Buffer-reusing counterpart of [`get_all_mammals`](Self::get_all_mammals).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let mut mammals = Vec::new();
+    ///
+    /// ps.collect_mammals_into(&mut mammals);
+    /// assert_eq!(mammals.len(), 5);
+    /// assert!(mammals[0].is_dog());
+    /// ```
+    pub fn collect_mammals_into(&self, out: &mut Vec<Mammal>) {
+        out.clear();
+        self.extend_mammals_matching(out, |_, _| true);
+    }
+
+    /// Clears `out` and fills it with the mammals of the given hair color.
+    ///
+    /// Buffer-reusing counterpart of
+    /// [`get_mammals_by_hair_color`](Self::get_mammals_by_hair_color). The
+    /// filter is applied during the join, so non-matching pets are never
+    /// composed into domain objects.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let mut mammals = Vec::new();
+    ///
+    /// ps.collect_mammals_by_hair_color_into("blond", &mut mammals);
+    /// assert_eq!(mammals.len(), 3);
+    ///
+    /// ps.collect_mammals_by_hair_color_into("gray", &mut mammals);
+    /// assert_eq!(mammals.len(), 1);
+    /// ```
+    pub fn collect_mammals_by_hair_color_into(&self, hair_color: &str, out: &mut Vec<Mammal>) {
+        out.clear();
+        self.extend_mammals_matching(out, |_, mammal| {
+            hair_color_matches(&mammal.hair_color, hair_color)
+        });
+    }
+
+    /// Shared join behind the dog queries.
+    ///
+    /// `keep` sees the Mammal and Dog rows before anything is composed, so
+    /// leaf-table predicates filter inside the join.
+    fn extend_dogs_matching(
+        &self,
+        out: &mut impl Extend<Dog>,
+        keep: impl Fn(&MammalComponent, &DogComponent) -> bool,
+    ) {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        // Join query - only entities with all three components
+        let mut rows: Vec<_> = (
+            &fetched_dogs,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .filter(|(dog, mammal, _, _, _)| keep(mammal, dog))
+            .collect();
+        rows.sort_by_key(|(_, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        // Using From trait implementations for cleaner conversions
+        out.extend(rows.into_iter().map(|(dog, mammal, pet, _, _)| Dog {
+            pet: pet.into(),
+            mammal: mammal.into(),
+            dog_specific: dog.into(),
+        }));
+    }
+
+    /// Shared join behind the cat queries; see
+    /// [`extend_dogs_matching`](Self::extend_dogs_matching).
+    fn extend_cats_matching(
+        &self,
+        out: &mut impl Extend<Cat>,
+        keep: impl Fn(&MammalComponent, &CatComponent) -> bool,
+    ) {
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_cats,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .filter(|(cat, mammal, _, _, _)| keep(mammal, cat))
+            .collect();
+        rows.sort_by_key(|(_, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        out.extend(rows.into_iter().map(|(cat, mammal, pet, _, _)| Cat {
+            pet: pet.into(),
+            mammal: mammal.into(),
+            cat_specific: cat.into(),
+        }));
+    }
+
+    /// Shared join behind the hamster queries; see
+    /// [`extend_dogs_matching`](Self::extend_dogs_matching).
+    fn extend_hamsters_matching(
+        &self,
+        out: &mut impl Extend<Hamster>,
+        keep: impl Fn(&MammalComponent, &HamsterComponent) -> bool,
+    ) {
+        let fetched_hamsters = self.ecs.read_storage::<HamsterComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_hamsters,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .filter(|(hamster, mammal, _, _, _)| keep(mammal, hamster))
+            .collect();
+        rows.sort_by_key(|(_, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        out.extend(
+            rows.into_iter()
+                .map(|(hamster, mammal, pet, _, _)| Hamster {
+                    pet: pet.into(),
+                    mammal: mammal.into(),
+                    hamster_specific: hamster.into(),
+                }),
+        );
+    }
+
+    /// Shared join behind the snake queries; see
+    /// [`extend_dogs_matching`](Self::extend_dogs_matching).
+    fn extend_snakes_matching(
+        &self,
+        out: &mut impl Extend<Snake>,
+        keep: impl Fn(&ReptileComponent, &SnakeComponent) -> bool,
+    ) {
+        let fetched_snakes = self.ecs.read_storage::<SnakeComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_reptiles = self.ecs.read_storage::<ReptileComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_snakes,
+            &fetched_reptiles,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .filter(|(snake, reptile, _, _, _)| keep(reptile, snake))
+            .collect();
+        rows.sort_by_key(|(_, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        out.extend(rows.into_iter().map(|(snake, reptile, pet, _, _)| Snake {
+            pet: pet.into(),
+            reptile: reptile.into(),
+            snake_specific: snake.into(),
+        }));
+    }
+
+    /// Shared join behind the mammal-level queries.
+    ///
+    /// `keep` sees the `MammalComponent` row before anything is cloned, and
+    /// matches are appended to `out` (which is not cleared) in insertion
+    /// order, all leaf types interleaved.
+    fn extend_mammals_matching(
+        &self,
+        out: &mut impl Extend<Mammal>,
+        keep: impl Fn(&PetComponent, &MammalComponent) -> bool,
+    ) {
+        out.extend(
+            self.mammal_rows_by_insertion(keep)
+                .into_iter()
+                .map(|(_, mammal)| mammal),
+        );
+    }
+
+    /// Visible mammals passing `keep`, paired with their insertion sequence
+    /// number and sorted by it.
+    fn mammal_rows_by_insertion(
+        &self,
+        keep: impl Fn(&PetComponent, &MammalComponent) -> bool,
+    ) -> Vec<(u64, Mammal)> {
+        let mut out = Vec::new();
+        self.visit_mammal_rows(|_, seq, leaf, mammal, pet| {
+            if keep(pet, mammal) {
+                out.push((seq, leaf.compose(mammal, pet)));
+            }
+        });
+        out
+    }
+
+    /// Hands every visible mammal row, with its entity and insertion
+    /// sequence number, to `visit` in insertion order, before anything is
+    /// composed.
+    ///
+    /// Shared by the mammal-level queries so they all agree on ordering;
+    /// callers compose only the rows they keep.
+    fn visit_mammal_rows(
+        &self,
+        visit: impl FnMut(Entity, u64, LeafRow<'_>, &MammalComponent, &PetComponent),
+    ) {
+        self.visit_mammal_rows_excluding(self.hidden_entities(), visit);
+    }
+
+    /// [`visit_mammal_rows`](Self::visit_mammal_rows) with a caller-built
+    /// exclusion set, so anti-joins happen inside the join.
+    ///
+    /// `hidden` replaces [`hidden_entities`](Self::hidden_entities); callers
+    /// start from it and add their own masks.
+    fn visit_mammal_rows_excluding(
+        &self,
+        hidden: BitSet,
+        mut visit: impl FnMut(Entity, u64, LeafRow<'_>, &MammalComponent, &PetComponent),
+    ) {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_hamsters = self.ecs.read_storage::<HamsterComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let entities = self.ecs.entities();
+
+        let dogs = (
+            &entities,
+            &fetched_dogs,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .map(|(entity, dog, mammal, pet, sequence, _)| {
+                (entity, LeafRow::Dog(dog), mammal, pet, sequence)
+            });
+        let cats = (
+            &entities,
+            &fetched_cats,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .map(|(entity, cat, mammal, pet, sequence, _)| {
+                (entity, LeafRow::Cat(cat), mammal, pet, sequence)
+            });
+        let hamsters = (
+            &entities,
+            &fetched_hamsters,
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .map(|(entity, hamster, mammal, pet, sequence, _)| {
+                (entity, LeafRow::Hamster(hamster), mammal, pet, sequence)
+            });
+
+        let mut rows: Vec<_> = dogs.chain(cats).chain(hamsters).collect();
+        rows.sort_by_key(|(_, _, _, _, sequence)| sequence.map(|s| s.seq));
+
+        for (entity, leaf, mammal, pet, sequence) in rows {
+            visit(entity, sequence.map_or(0, |s| s.seq), leaf, mammal, pet);
+        }
+    }
+
+    /// Gets mammals filtered by hair color into a [`CompactMammals`] buffer.
+    ///
+    /// Same results as [`get_mammals_by_hair_color`](Self::get_mammals_by_hair_color),
+    /// but short result lists (up to four pets) are stored inline and never
+    /// touch the heap. Requires the `compact` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "compact")] {
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let gray = ps.get_mammals_by_hair_color_compact("gray");
+    /// assert_eq!(gray.len(), 1);
+    /// assert!(!gray.spilled());
+    /// # }
+    /// ```
+    #[cfg(feature = "compact")]
+    pub fn get_mammals_by_hair_color_compact(&self, hair_color: &str) -> CompactMammals {
+        let mut mammals = CompactMammals::new();
+        self.extend_mammals_matching(&mut mammals, |_, mammal| {
+            hair_color_matches(&mammal.hair_color, hair_color)
+        });
+        mammals
+    }
+
+    /// Picks up to `n` visible mammals uniformly at random.
+    ///
+    /// Reservoir-samples entity indices, then composes only the chosen
+    /// mammals, so the full collection is never built. Results come back in
+    /// no particular order. Requires the `sampling` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "sampling")] {
+    /// use ecs_inheritance_patterns::testing;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// let ps = testing::sample_state_with(100, 100);
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    ///
+    /// assert_eq!(ps.sample_mammals(5, &mut rng).len(), 5);
+    /// assert_eq!(ps.sample_mammals(500, &mut rng).len(), 200);
+    /// # }
+    /// ```
+    #[cfg(feature = "sampling")]
+    pub fn sample_mammals(&self, n: usize, rng: &mut impl rand::Rng) -> Vec<Mammal> {
+        use rand::seq::IteratorRandom;
+
+        let rows = {
+            let fetched_pets = self.ecs.read_storage::<PetComponent>();
+            let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+            self.visible_rows(&[fetched_mammals.mask(), fetched_pets.mask()])
+        };
+        let entities = self.ecs.entities();
+
+        rows.into_iter()
+            .sample(rng, n)
+            .into_iter()
+            .filter_map(|id| self.mammal_at(entities.entity(id)))
+            .collect()
+    }
+
+    // ========================================================================
+    // Aggregation
+    // ========================================================================
+
+    /// Computes an aggregate over the mammals matching `filter` in one pass.
+    ///
+    /// Reads the component storages directly, so no `Dog`, `Cat`, or `Mammal`
+    /// values (and none of their `String` clones) are built along the way.
+    ///
+    /// Returns `None` when `Sum`/`Avg` has no non-`NULL` input, matching SQL.
+    /// `Count` always returns `Some`.
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT AVG(Dog.num_commands_known) FROM Mammal
+    /// LEFT JOIN Dog ON Mammal.id = Dog.mammal_id
+    /// WHERE Mammal.hair_color = 'blond'
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let blond = MammalFilter::HairColor("blond".to_string());
+    ///
+    /// assert_eq!(ps.aggregate(&blond, Aggregator::Count), Some(3.0));
+    /// assert_eq!(
+    ///     ps.aggregate(&blond, Aggregator::Avg(NumericField::NumCommandsKnown)),
+    ///     Some(30.0)
+    /// );
+    ///
+    /// let black = MammalFilter::HairColor("black".to_string());
+    /// assert_eq!(ps.aggregate(&black, Aggregator::Sum(NumericField::TailLength)), None);
+    /// ```
+    pub fn aggregate(&self, filter: &MammalFilter, aggregator: Aggregator) -> Option<f64> {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        let rows = (
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_dogs.maybe(),
+            !&hidden,
+        )
+            .join()
+            .filter(|(mammal, _, _, _)| filter_matches(filter, mammal));
+
+        let field = match aggregator {
+            Aggregator::Count => return Some(rows.count() as f64),
+            Aggregator::Sum(field) | Aggregator::Avg(field) => field,
+        };
+
+        // Dog-only columns are NULL for every other leaf type
+        let (sum, n) = rows
+            .filter_map(|(_, _, dog, _)| dog.map(|dog| numeric_value(field, dog)))
+            .fold((0.0, 0usize), |(sum, n), value| (sum + value, n + 1));
+
+        match (aggregator, n) {
+            (_, 0) => None,
+            (Aggregator::Avg(_), n) => Some(sum / n as f64),
+            _ => Some(sum),
+        }
+    }
+
+    /// Counts visible dogs without composing any of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.count_dogs(), 3);
+    /// assert_eq!(ps.count_cats(), 2);
+    /// assert_eq!(ps.count_mammals(), 5);
+    /// ```
+    pub fn count_dogs(&self) -> usize {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        (&fetched_dogs, &fetched_mammals, &fetched_pets, !&hidden)
+            .join()
+            .count()
+    }
+
+    /// Counts visible cats without composing any of them.
+    pub fn count_cats(&self) -> usize {
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        (&fetched_cats, &fetched_mammals, &fetched_pets, !&hidden)
+            .join()
+            .count()
+    }
+
+    /// Counts visible hamsters without composing any of them.
+    pub fn count_hamsters(&self) -> usize {
+        let fetched_hamsters = self.ecs.read_storage::<HamsterComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        (&fetched_hamsters, &fetched_mammals, &fetched_pets, !&hidden)
+            .join()
+            .count()
+    }
+
+    /// Counts visible mammals of every leaf type.
+    ///
+    /// See [`count_where`](Self::count_where) to count only those matching a
+    /// [`Spec`](crate::pet_module::Spec).
+    pub fn count_mammals(&self) -> usize {
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        (&fetched_mammals, &fetched_pets, !&hidden).join().count()
+    }
+
+    /// Counts visible mammals per breed, most common first.
+    ///
+    /// One pass over the `Mammal` storage, counting borrowed breed names, so
+    /// each distinct breed is cloned only once. Mammals with an unknown
+    /// (`NULL`) breed are left out. Breeds with equal counts are ordered by
+    /// name.
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT breed, COUNT(*) FROM Mammal
+    /// WHERE breed IS NOT NULL
+    /// GROUP BY breed
+    /// ORDER BY COUNT(*) DESC, breed
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(
+    ///     ps.breed_distribution(),
+    ///     [
+    ///         ("schnauzer".to_string(), 2),
+    ///         ("shorthair".to_string(), 2),
+    ///         ("labrador".to_string(), 1),
+    ///     ]
+    /// );
+    /// ```
+    pub fn breed_distribution(&self) -> Vec<(String, usize)> {
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for (mammal, _, _) in (&fetched_mammals, &fetched_pets, !&hidden).join() {
+            if let Some(breed) = mammal.breed.as_deref() {
+                *counts.entry(breed).or_default() += 1;
+            }
+        }
+
+        let mut distribution: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(breed, count)| (breed.to_string(), count))
+            .collect();
+        distribution.sort_unstable_by(|(a, a_count), (b, b_count)| {
+            b_count.cmp(a_count).then_with(|| a.cmp(b))
+        });
+        distribution
+    }
+
+    /// Returns the distinct breeds of visible mammals, sorted.
+    ///
+    /// One pass over the `Mammal` storage; each breed is cloned once.
+    /// `NULL` breeds are left out.
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT DISTINCT breed FROM Mammal WHERE breed IS NOT NULL ORDER BY breed
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let breeds: Vec<_> = ps.distinct_breeds().into_iter().collect();
+    /// assert_eq!(breeds, ["labrador", "schnauzer", "shorthair"]);
+    /// ```
+    pub fn distinct_breeds(&self) -> BTreeSet<String> {
+        self.distinct_mammal_values(|mammal| mammal.breed.as_deref())
+    }
+
+    /// Returns the distinct hair colors of visible mammals, sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let colors: Vec<_> = ps.distinct_hair_colors().into_iter().collect();
+    /// assert_eq!(colors, ["black", "blond", "gray"]);
+    /// ```
+    pub fn distinct_hair_colors(&self) -> BTreeSet<String> {
+        self.distinct_mammal_values(|mammal| Some(mammal.hair_color.as_str()))
+    }
+
+    /// Collects the distinct values `column` reads from visible `Mammal` rows.
+    fn distinct_mammal_values(
+        &self,
+        column: impl Fn(&MammalComponent) -> Option<&str>,
+    ) -> BTreeSet<String> {
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_entities();
+
+        let values: BTreeSet<&str> = (&fetched_mammals, &fetched_pets, !&hidden)
+            .join()
+            .filter_map(|(mammal, _, _)| column(mammal))
+            .collect();
+        values.into_iter().map(str::to_string).collect()
+    }
+
+    /// Computes headline counts, reading each storage once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::WaterType;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let simba = ps.find_first_by_name("Simba").unwrap().uuid().to_string();
+    /// ps.soft_delete(&simba).unwrap();
+    ///
+    /// let stats = ps.summary();
+    /// assert_eq!((stats.dogs, stats.cats, stats.mammals()), (3, 1, 4));
+    /// assert_eq!(stats.distinct_breeds, 3);
+    /// assert_eq!(stats.distinct_hair_colors, 3);
+    /// assert_eq!(stats.total_entities, 5);
+    ///
+    /// ps.create_snake("Kaa", "green", true, 3.5);
+    /// ps.create_goldfish("Bubbles", WaterType::Fresh, 7, true);
+    /// let stats = ps.summary();
+    /// assert_eq!((stats.snakes, stats.reptiles(), stats.fish), (1, 1, 1));
+    /// assert_eq!(stats.total_entities, 7);
+    ///
+    /// let shelter = ps.add_shelter("Riverside", 0);
+    /// for dog in ps.get_all_dogs() {
+    ///     ps.enqueue(&shelter, &dog.pet.uuid).unwrap();
+    /// }
+    /// assert_eq!(ps.summary().waiting, 3);
+    /// ```
+    pub fn summary(&self) -> PetStateStats {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_cats = self.ecs.read_storage::<CatComponent>();
+        let fetched_hamsters = self.ecs.read_storage::<HamsterComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_turtles = self.ecs.read_storage::<TurtleComponent>();
+        let fetched_snakes = self.ecs.read_storage::<SnakeComponent>();
+        let fetched_fish = self.ecs.read_storage::<FishComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut stats = PetStateStats {
+            turtles: (&fetched_turtles, &fetched_pets, !&hidden).join().count(),
+            snakes: (&fetched_snakes, &fetched_pets, !&hidden).join().count(),
+            fish: (&fetched_fish, &fetched_pets, !&hidden).join().count(),
+            total_entities: fetched_pets.join().count(),
+            waiting: self.waiting_count(),
+            ..PetStateStats::default()
+        };
+        let mut breeds = HashSet::new();
+        let mut hair_colors = HashSet::new();
+
+        for (mammal, _, dog, cat, hamster, _) in (
+            &fetched_mammals,
+            &fetched_pets,
+            fetched_dogs.mask().maybe(),
+            fetched_cats.mask().maybe(),
+            fetched_hamsters.mask().maybe(),
+            !&hidden,
+        )
+            .join()
+        {
+            match (dog, cat, hamster) {
+                (Some(_), _, _) => stats.dogs += 1,
+                (_, Some(_), _) => stats.cats += 1,
+                (_, _, Some(_)) => stats.hamsters += 1,
+                _ => {}
+            }
+            breeds.extend(mammal.breed.as_deref());
+            hair_colors.insert(mammal.hair_color.as_str());
+        }
+
+        stats.distinct_breeds = breeds.len();
+        stats.distinct_hair_colors = hair_colors.len();
+        stats
+    }
+
+    /// Buckets visible mammals by their hair color.
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT hair_color, ... FROM Mammal GROUP BY hair_color
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let groups = ps.group_mammals_by_hair_color();
+    ///
+    /// assert_eq!(groups.len(), 3);
+    /// assert_eq!(groups["blond"].len(), 3);
+    /// assert_eq!(groups["gray"][0].name(), "Shippen");
+    /// ```
+    pub fn group_mammals_by_hair_color(&self) -> HashMap<String, Vec<Mammal>> {
+        self.group_by(|mammal| mammal.hair_color().to_string())
+    }
+
+    /// Buckets visible mammals by an arbitrary key.
+    ///
+    /// Within each bucket, mammals keep the order of
+    /// [`iter_mammals`](Self::iter_mammals): creation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let by_sound = ps.group_by(|mammal| mammal.make_sound());
+    ///
+    /// assert_eq!(by_sound["Woof!"].len(), 3);
+    /// assert_eq!(by_sound["Meow!"].len(), 2);
+    /// ```
+    pub fn group_by<K: Eq + Hash>(&self, key: impl Fn(&Mammal) -> K) -> HashMap<K, Vec<Mammal>> {
+        let mut groups: HashMap<K, Vec<Mammal>> = HashMap::new();
+        for mammal in self.iter_mammals() {
+            groups.entry(key(&mammal)).or_default().push(mammal);
+        }
+        groups
+    }
+
+    // ========================================================================
+    // Diagnostics
+    // ========================================================================
+
+    /// Reports how densely the component storages are packed.
+    ///
+    /// Counts live entities and the vacant entity slots below the highest
+    /// live one. See [`StorageReport`] for interpreting the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state_with(10, 0);
+    /// let ids: Vec<String> = ps.get_all_dogs().into_iter().map(|d| d.pet.uuid).collect();
+    ///
+    /// // Delete everything but the last dog
+    /// for id in &ids[..9] {
+    ///     ps.delete(id);
+    /// }
+    ///
+    /// let report = ps.storage_report();
+    /// assert_eq!(report.live_entities, 1);
+    /// assert_eq!(report.vacant_slots, 9);
+    /// assert!(report.is_fragmented());
+    /// ```
+    pub fn storage_report(&self) -> StorageReport {
+        let entities = self.ecs.entities();
+
+        // Counts every entity (pets and associated rows such as policies),
+        // since they all share the same slot space
+        let (live, highest) = entities
+            .join()
+            .fold((0usize, None), |(live, highest), entity| {
+                (live + 1, highest.max(Some(entity.id())))
+            });
+        let slots = highest.map_or(0, |id| id as usize + 1);
+
+        StorageReport {
+            live_entities: live,
+            vacant_slots: slots - live,
+        }
+    }
+
+    /// Returns the current storage fragmentation, from `0.0` to `1.0`.
+    ///
+    /// Shorthand for `storage_report().fragmentation()`. Creating new pets
+    /// refills vacant slots, so fragmentation drops again after churn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    ///
+    /// // Heavy create/delete churn, keeping one survivor per round
+    /// for round in 0..50 {
+    ///     let mut ids = Vec::new();
+    ///     for i in 0..4 {
+    ///         let id;
+    ///         (ps, id) = Dog::create(ps, format!("Pup {round}-{i}"), "brown", "mutt", true, 5.0, 1);
+    ///         ids.push(id);
+    ///     }
+    ///     for id in &ids[..3] {
+    ///         ps.delete(id);
+    ///     }
+    /// }
+    ///
+    /// // Freed slots were reused each round, so the world stays compact
+    /// assert_eq!(ps.get_all_dogs().len(), 50);
+    /// assert!(ps.fragmentation() < 0.1);
+    /// ```
+    pub fn fragmentation(&self) -> f32 {
+        self.storage_report().fragmentation()
+    }
+
+    /// Lists every component attached to the pet with the given UUID.
+    ///
+    /// Meant for debugging: see [`EntityDescription`] for the format.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no pet has that UUID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, tom) = Cat::create(ps, "Tom", "gray", "tabby", true, false, true);
+    /// ps.mark_deceased(&tom, Date::new(2024, 3, 1));
+    ///
+    /// let tables: Vec<_> = ps
+    ///     .describe_entity(&tom)
+    ///     .unwrap()
+    ///     .components
+    ///     .iter()
+    ///     .map(|c| c.table)
+    ///     .collect();
+    /// assert_eq!(tables, ["Pet", "Mammal", "Cat", "Lifecycle"]);
+    /// ```
+    pub fn describe_entity(&self, uuid: &str) -> Option<EntityDescription> {
+        let entity = self.find_entity(uuid)?;
+        let mut components = Vec::new();
+
+        self.describe_component::<PetComponent>(entity, "Pet", &mut components);
+        self.describe_component::<MammalComponent>(entity, "Mammal", &mut components);
+        self.describe_component::<ReptileComponent>(entity, "Reptile", &mut components);
+        self.describe_component::<DogComponent>(entity, "Dog", &mut components);
+        self.describe_component::<WorkingDogComponent>(entity, "WorkingDog", &mut components);
+        self.describe_component::<ServiceDogComponent>(entity, "ServiceDog", &mut components);
+        self.describe_component::<HerdingDogComponent>(entity, "HerdingDog", &mut components);
+        self.describe_component::<CatComponent>(entity, "Cat", &mut components);
+        self.describe_component::<HamsterComponent>(entity, "Hamster", &mut components);
+        self.describe_component::<TurtleComponent>(entity, "Turtle", &mut components);
+        self.describe_component::<SnakeComponent>(entity, "Snake", &mut components);
+        self.describe_component::<FishComponent>(entity, "Fish", &mut components);
+        self.describe_component::<GoldfishComponent>(entity, "Goldfish", &mut components);
+        self.describe_component::<BettaComponent>(entity, "Betta", &mut components);
+        self.describe_component::<LifecycleComponent>(entity, "Lifecycle", &mut components);
+        self.describe_component::<TrainedComponent>(entity, "Trained", &mut components);
+        self.describe_component::<VaccinatedComponent>(entity, "Vaccinated", &mut components);
+        self.describe_component::<AdoptedComponent>(entity, "Adopted", &mut components);
+        self.describe_component::<HousedComponent>(entity, "Housed", &mut components);
+        self.describe_component::<DeletedComponent>(entity, "Deleted", &mut components);
+
+        Some(EntityDescription {
+            uuid: uuid.to_string(),
+            components,
+        })
+    }
+
+    /// Appends `C` to `out` if `entity` has it.
+    fn describe_component<C: Component + fmt::Debug>(
+        &self,
+        entity: Entity,
+        table: &'static str,
+        out: &mut Vec<ComponentDescription>,
+    ) {
+        if let Some(component) = self.ecs.read_storage::<C>().get(entity) {
+            out.push(ComponentDescription {
+                table,
+                value: format!("{component:?}"),
+            });
+        }
+    }
+
+    /// Retrieves a specific dog by UUID (demonstrates lazy loading pattern).
+    ///
+    /// This method demonstrates how to implement efficient single-entity queries,
+    /// which would be the foundation for lazy loading patterns in larger applications.
+    ///
+    /// # Performance
+    ///
+    /// Resolves the UUID through the state's primary-key index and reads the
+    /// three components of that one entity, so the cost does not grow with
+    /// the number of pets.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Dog)` if found, `None` if no dog with that UUID exists.
+    ///
+    /// # Example Use Case
+    ///
+    /// In a web application, you might load a dog by ID from a URL parameter:
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let url_param = ps.create_dog("Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// let dog = ps.get_dog_by_id(&url_param).unwrap();
+    /// assert_eq!(dog.pet.name, "Rex");
+    /// assert!(ps.get_dog_by_id("missing").is_none());
+    /// ```
+    pub fn get_dog_by_id(&self, uuid: &str) -> Option<Dog> {
+        match self.get_mammal_by_id(uuid)? {
+            Mammal::Dog(dog) => Some(dog),
+            _ => None,
+        }
+    }
+
+    /// Retrieves a specific cat by UUID.
+    ///
+    /// Cat counterpart of [`get_dog_by_id`](Self::get_dog_by_id).
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Cat)` if found, `None` if no cat with that UUID exists.
+    pub fn get_cat_by_id(&self, uuid: &str) -> Option<Cat> {
+        match self.get_mammal_by_id(uuid)? {
+            Mammal::Cat(cat) => Some(cat),
+            _ => None,
+        }
+    }
+
+    /// Retrieves a specific hamster by UUID.
+    ///
+    /// Hamster counterpart of [`get_dog_by_id`](Self::get_dog_by_id).
+    pub fn get_hamster_by_id(&self, uuid: &str) -> Option<Hamster> {
+        match self.get_mammal_by_id(uuid)? {
+            Mammal::Hamster(hamster) => Some(hamster),
+            _ => None,
+        }
+    }
+
+    /// Retrieves any mammal by UUID, whatever its leaf type.
+    ///
+    /// Finds the entity through the shared Pet table, then checks which leaf
+    /// component it carries to build the right [`Mammal`] variant.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no mammal with that UUID exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let tom = ps.create_cat("Tom", "gray", "tabby", true, false, true);
+    ///
+    /// let mammal = ps.get_mammal_by_id(&tom).unwrap();
+    /// assert!(mammal.is_cat());
+    /// assert_eq!(ps.get_cat_by_id(&tom).as_ref(), mammal.as_cat());
+    /// assert!(ps.get_dog_by_id(&tom).is_none());
+    /// ```
+    pub fn get_mammal_by_id(&self, uuid: &str) -> Option<Mammal> {
+        let entity = self.find_entity(uuid)?;
+        self.mammal_at(entity)
+    }
+
+    /// Retrieves a specific turtle by UUID.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no turtle with that UUID exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let shelly = ps.create_turtle("Shelly", "olive", false, true, false);
+    /// let rex = ps.create_dog("Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// assert_eq!(ps.get_turtle_by_id(&shelly).unwrap().pet.name, "Shelly");
+    /// assert!(ps.get_turtle_by_id(&rex).is_none());
+    /// ```
+    pub fn get_turtle_by_id(&self, uuid: &str) -> Option<Turtle> {
+        let entity = self.find_entity(uuid)?;
+        let fetched_turtles = self.ecs.read_storage::<TurtleComponent>();
+        let fetched_reptiles = self.ecs.read_storage::<ReptileComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+
+        Some(Turtle {
+            pet: fetched_pets.get(entity)?.into(),
+            reptile: fetched_reptiles.get(entity)?.into(),
+            turtle_specific: fetched_turtles.get(entity)?.into(),
+        })
+    }
+
+    /// Retrieves a specific snake by UUID.
+    ///
+    /// Snake counterpart of [`get_turtle_by_id`](Self::get_turtle_by_id).
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no snake with that UUID exists.
+    pub fn get_snake_by_id(&self, uuid: &str) -> Option<Snake> {
+        let entity = self.find_entity(uuid)?;
+        let fetched_snakes = self.ecs.read_storage::<SnakeComponent>();
+        let fetched_reptiles = self.ecs.read_storage::<ReptileComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+
+        Some(Snake {
+            pet: fetched_pets.get(entity)?.into(),
+            reptile: fetched_reptiles.get(entity)?.into(),
+            snake_specific: fetched_snakes.get(entity)?.into(),
+        })
+    }
+}
+
+// ============================================================================
+// Leaf Rows
+// ============================================================================
+
+/// Borrowed leaf component of any mammal species, for joins that chain the
+/// dog, cat and hamster rows into one stream and compose only the rows that
+/// survive.
+enum LeafRow<'a> {
+    Dog(&'a DogComponent),
+    Cat(&'a CatComponent),
+    Hamster(&'a HamsterComponent),
+}
+
+impl LeafRow<'_> {
+    /// Builds the domain object from this leaf row and its shared rows.
+    fn compose(self, mammal: &MammalComponent, pet: &PetComponent) -> Mammal {
+        match self {
+            LeafRow::Dog(dog) => Mammal::Dog(Dog {
+                pet: pet.into(),
+                mammal: mammal.into(),
+                dog_specific: dog.into(),
+            }),
+            LeafRow::Cat(cat) => Mammal::Cat(Cat {
+                pet: pet.into(),
+                mammal: mammal.into(),
+                cat_specific: cat.into(),
+            }),
+            LeafRow::Hamster(hamster) => Mammal::Hamster(Hamster {
+                pet: pet.into(),
+                mammal: mammal.into(),
+                hamster_specific: hamster.into(),
+            }),
+        }
+    }
+}
+
+// ============================================================================
+// Name Index
+// Secondary index over Pet.name, maintained by every insert, rename and delete
+// ============================================================================
+
+/// Entities per pet name; entries are removed once their list is empty.
+type NameIndex = HashMap<String, Vec<Entity>>;
+
+/// Records that `entity` is named `name`.
+fn index_name(index: &mut NameIndex, name: String, entity: Entity) {
+    index.entry(name).or_default().push(entity);
+}
+
+/// Forgets that `entity` is named `name`.
+fn unindex_name(index: &mut NameIndex, name: &str, entity: Entity) {
+    if let Some(entities) = index.get_mut(name) {
+        entities.retain(|&e| e != entity);
+        if entities.is_empty() {
+            index.remove(name);
+        }
+    }
+}
+
+// ============================================================================
+// Component-Level Predicates
+// Evaluated inside joins so filtering never needs composed domain objects
+// ============================================================================
+
+/// Returns `true` if the `Mammal` row satisfies `filter`.
+fn filter_matches(filter: &MammalFilter, mammal: &MammalComponent) -> bool {
+    match filter {
+        MammalFilter::All => true,
+        MammalFilter::HairColor(hair_color) => hair_color_matches(&mammal.hair_color, hair_color),
+        MammalFilter::Breed(breed) => mammal.breed.as_ref() == Some(breed),
+    }
+}
+
+/// Returns `true` if a stored hair color equals `query` once `query` is
+/// normalized the same way stored values are.
+///
+/// Compares character by character, so no string is allocated per row.
+fn hair_color_matches(stored: &str, query: &str) -> bool {
+    query
+        .trim()
+        .chars()
+        .flat_map(char::to_lowercase)
+        .eq(stored.chars())
+}
+
+/// Canonical stored form of a hair color: trimmed and lowercased.
+fn normalize_hair_color(hair_color: &str) -> String {
+    hair_color
+        .trim()
+        .chars()
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Reads a numeric `Dog` column as `f64`.
+fn numeric_value(field: NumericField, dog: &DogComponent) -> f64 {
+    match field {
+        NumericField::TailLength => dog.tail_length,
+        NumericField::NumCommandsKnown => f64::from(dog.num_commands_known),
     }
 }
@@ -0,0 +1,124 @@
+//! Entry API for get-or-create by name.
+//!
+//! Mirrors [`std::collections::hash_map::Entry`]: look a pet up once, then
+//! either use the existing one or insert a new one, without a separate
+//! query and insert in caller code.
+
+use super::{PetComponent, PetState};
+use crate::pet_module::{CatInput, DogInput};
+use specs::prelude::*;
+
+/// A pet looked up by name, which may or may not exist yet.
+///
+/// Obtained from [`PetState::entry_by_name`].
+pub struct PetEntry<'a> {
+    ps: &'a mut PetState,
+    name: String,
+    uuid: Option<String>,
+}
+
+impl PetEntry<'_> {
+    /// UUID of the existing pet, or `None` if the entry is vacant.
+    pub fn uuid(&self) -> Option<&str> {
+        self.uuid.as_deref()
+    }
+
+    /// Returns the existing pet's UUID, or creates a dog and returns its UUID.
+    ///
+    /// `default` is only called when no pet has the entry's name. The
+    /// entry's name replaces whatever name the returned input carries, so
+    /// repeated calls stay idempotent.
+    ///
+    /// An existing pet is returned as is, even if it is not a dog.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new dog exceeds the configured
+    /// [`PetStateOptions`](crate::pet_module::PetStateOptions), like
+    /// [`PetState::create_dog`].
+    pub fn or_insert_dog(self, default: impl FnOnce() -> DogInput) -> String {
+        if let Some(uuid) = self.uuid {
+            return uuid;
+        }
+        let dog = default();
+        self.ps.create_dog(
+            self.name,
+            dog.hair_color,
+            dog.breed,
+            dog.has_hair,
+            dog.tail_length,
+            dog.num_commands_known,
+        )
+    }
+
+    /// Returns the existing pet's UUID, or creates a cat and returns its UUID.
+    ///
+    /// Cat counterpart of [`or_insert_dog`](Self::or_insert_dog).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new cat exceeds the configured
+    /// [`PetStateOptions`](crate::pet_module::PetStateOptions).
+    pub fn or_insert_cat(self, default: impl FnOnce() -> CatInput) -> String {
+        if let Some(uuid) = self.uuid {
+            return uuid;
+        }
+        let cat = default();
+        self.ps.create_cat(
+            self.name,
+            cat.hair_color,
+            cat.breed,
+            cat.has_hair,
+            cat.declawed,
+            cat.sits_on_keyboard,
+        )
+    }
+}
+
+impl PetState {
+    /// Looks up a pet by exact name for in-place get-or-create.
+    ///
+    /// If several pets share the name, the entry refers to the first one
+    /// found. Like UUID lookups, this ignores visibility settings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// fn buddy() -> DogInput {
+    ///     DogInput {
+    ///         name: "Buddy".to_string(),
+    ///         hair_color: "golden".to_string(),
+    ///         breed: Some("retriever".to_string()),
+    ///         has_hair: true,
+    ///         tail_length: 12.0,
+    ///         num_commands_known: 8,
+    ///     }
+    /// }
+    ///
+    /// let mut ps = PetState::new();
+    /// let first = ps.entry_by_name("Buddy").or_insert_dog(buddy);
+    /// let second = ps.entry_by_name("Buddy").or_insert_dog(buddy);
+    ///
+    /// assert_eq!(first, second);
+    /// assert_eq!(ps.get_all_dogs().len(), 1);
+    /// ```
+    pub fn entry_by_name(&mut self, name: impl Into<String>) -> PetEntry<'_> {
+        let name = name.into();
+        let uuid = self
+            .by_name
+            .get(&name)
+            .and_then(|entities| entities.first())
+            .and_then(|&entity| {
+                let fetched_pets = self.ecs.read_storage::<PetComponent>();
+                fetched_pets.get(entity).map(|pet| pet.uuid.clone())
+            });
+
+        PetEntry {
+            ps: self,
+            name,
+            uuid,
+        }
+    }
+}
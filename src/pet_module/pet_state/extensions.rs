@@ -0,0 +1,144 @@
+//! Extension components defined outside this crate.
+//!
+//! Downstream code can add its own "tables" without touching the ECS world
+//! directly: any `Send + Sync + 'static` type can be registered once and then
+//! attached to existing pets by UUID. Values are stored in a private wrapper
+//! component, so callers never implement specs traits themselves.
+//!
+//! # Component Mapping
+//!
+//! ```text
+//! ExtensionComponent<T> → one extra table per registered T (pet_id, value)
+//! ```
+
+use super::PetState;
+use crate::pet_module::PetError;
+use specs::prelude::*;
+use specs::storage::MaskedStorage;
+use std::any::type_name;
+
+/// Component wrapping a downstream extension value.
+///
+/// Maps to: one table per `T` with columns (pet_id, value)
+struct ExtensionComponent<T>(T);
+
+impl<T: Send + Sync + 'static> Component for ExtensionComponent<T> {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl PetState {
+    /// Registers `T` as an extension component.
+    ///
+    /// Must be called once per type before [`attach`](Self::attach);
+    /// registering the same type again is a no-op and keeps existing values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Microchip {
+    ///     code: String,
+    /// }
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// ps.register_extension_component::<Microchip>();
+    /// ps.attach(&rex, Microchip { code: "985-112".into() }).unwrap();
+    ///
+    /// assert_eq!(
+    ///     ps.get_extension::<Microchip>(&rex),
+    ///     Some(Microchip { code: "985-112".into() })
+    /// );
+    /// ```
+    pub fn register_extension_component<T: Send + Sync + 'static>(&mut self) {
+        if !self.is_extension_registered::<T>() {
+            self.ecs.register::<ExtensionComponent<T>>();
+        }
+    }
+
+    /// Attaches `value` to the pet with the given UUID, replacing any
+    /// previous `T` it had.
+    ///
+    /// # Errors
+    ///
+    /// - [`PetError::ExtensionNotRegistered`] if `T` was never registered
+    /// - [`PetError::PetNotFound`] if no pet has that UUID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// struct Microchip(u64);
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// assert!(matches!(
+    ///     ps.attach(&rex, Microchip(42)),
+    ///     Err(PetError::ExtensionNotRegistered(_))
+    /// ));
+    ///
+    /// ps.register_extension_component::<Microchip>();
+    /// assert_eq!(
+    ///     ps.attach("missing", Microchip(42)).err(),
+    ///     Some(PetError::PetNotFound("missing".to_string()))
+    /// );
+    /// ```
+    pub fn attach<T: Send + Sync + 'static>(
+        &mut self,
+        uuid: &str,
+        value: T,
+    ) -> Result<(), PetError> {
+        if !self.is_extension_registered::<T>() {
+            return Err(PetError::ExtensionNotRegistered(type_name::<T>()));
+        }
+        let entity = self
+            .find_entity(uuid)
+            .ok_or_else(|| PetError::PetNotFound(uuid.to_string()))?;
+        // Entity came from find_entity, so it's alive
+        let _ = self
+            .ecs
+            .write_storage::<ExtensionComponent<T>>()
+            .insert(entity, ExtensionComponent(value));
+        Ok(())
+    }
+
+    /// Removes the pet's `T` and returns it.
+    ///
+    /// Returns `None` if `T` is not registered, the pet doesn't exist, or
+    /// the pet has no `T` attached.
+    pub fn detach<T: Send + Sync + 'static>(&mut self, uuid: &str) -> Option<T> {
+        if !self.is_extension_registered::<T>() {
+            return None;
+        }
+        let entity = self.find_entity(uuid)?;
+        self.ecs
+            .write_storage::<ExtensionComponent<T>>()
+            .remove(entity)
+            .map(|ExtensionComponent(value)| value)
+    }
+
+    /// Returns a copy of the pet's `T`.
+    ///
+    /// Returns `None` if `T` is not registered, the pet doesn't exist, or
+    /// the pet has no `T` attached.
+    pub fn get_extension<T: Clone + Send + Sync + 'static>(&self, uuid: &str) -> Option<T> {
+        if !self.is_extension_registered::<T>() {
+            return None;
+        }
+        let entity = self.find_entity(uuid)?;
+        self.ecs
+            .read_storage::<ExtensionComponent<T>>()
+            .get(entity)
+            .map(|ExtensionComponent(value)| value.clone())
+    }
+
+    /// Returns `true` if `T` has been registered as an extension component.
+    fn is_extension_registered<T: Send + Sync + 'static>(&self) -> bool {
+        self.ecs.has_value::<MaskedStorage<ExtensionComponent<T>>>()
+    }
+}
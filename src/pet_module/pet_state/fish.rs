@@ -0,0 +1,449 @@
+//! The fish branch of the hierarchy and the tanks fish live in.
+//!
+//! `Fish` is a third middle level under `Pet`, next to `Mammal` and
+//! `Reptile`, with `Goldfish` and `Betta` as its leaves. Unlike the other
+//! branches, a fish row carries a reference to another entity: the tank it
+//! lives in. In SQL that's a nullable foreign key column on the `Fish`
+//! table; here it's an `Option<Entity>` stored on the fish's component.
+//!
+//! # Component Mapping
+//!
+//! ```text
+//! FishComponent     → Fish table (pet_id, water_type, fin_count, tank_id)
+//! GoldfishComponent → Goldfish table (fish_id, is_fancy)
+//! BettaComponent    → Betta table (fish_id, is_male)
+//! TankComponent     → Tank table (id, name, water_type)
+//! ```
+
+use super::{PetComponent, PetState, SequenceComponent, index_name};
+use crate::pet_module::{
+    Betta, BettaData, Fish, FishData, Goldfish, GoldfishData, PetError, Tank, WaterType,
+};
+use specs::prelude::*;
+use specs_derive::Component;
+
+/// Component representing the Fish table (middle level in hierarchy).
+///
+/// Maps to: `Fish` table with columns (id, pet_id, water_type, fin_count, tank_id)
+#[derive(Component, Debug, Clone)]
+#[storage(VecStorage)]
+pub(super) struct FishComponent {
+    water_type: WaterType,
+    fin_count: u32,
+    /// The tank this fish lives in; `None` is a NULL `tank_id`.
+    tank: Option<Entity>,
+}
+
+impl From<&FishComponent> for FishData {
+    fn from(comp: &FishComponent) -> Self {
+        Self {
+            water_type: comp.water_type,
+            fin_count: comp.fin_count,
+        }
+    }
+}
+
+/// Component representing the Goldfish table (leaf level in hierarchy).
+///
+/// Maps to: `Goldfish` table with columns (id, fish_id, is_fancy)
+#[derive(Component, Debug, Clone)]
+#[storage(VecStorage)]
+pub(super) struct GoldfishComponent {
+    is_fancy: bool,
+}
+
+impl From<&GoldfishComponent> for GoldfishData {
+    fn from(comp: &GoldfishComponent) -> Self {
+        Self {
+            is_fancy: comp.is_fancy,
+        }
+    }
+}
+
+/// Component representing the Betta table (leaf level in hierarchy).
+///
+/// Maps to: `Betta` table with columns (id, fish_id, is_male)
+#[derive(Component, Debug, Clone)]
+#[storage(VecStorage)]
+pub(super) struct BettaComponent {
+    is_male: bool,
+}
+
+impl From<&BettaComponent> for BettaData {
+    fn from(comp: &BettaComponent) -> Self {
+        Self {
+            is_male: comp.is_male,
+        }
+    }
+}
+
+/// Component representing the Tank table.
+///
+/// Maps to: `Tank` table with columns (id, name, water_type)
+#[derive(Component, Debug, Clone)]
+#[storage(DenseVecStorage)]
+pub(super) struct TankComponent {
+    uuid: String,
+    name: String,
+    water_type: WaterType,
+}
+
+impl From<&TankComponent> for Tank {
+    fn from(comp: &TankComponent) -> Self {
+        Self {
+            uuid: comp.uuid.clone(),
+            name: comp.name.clone(),
+            water_type: comp.water_type,
+        }
+    }
+}
+
+impl PetState {
+    /// Adds a goldfish to the ECS world, creating an entity with three components.
+    ///
+    /// The entity carries `PetComponent`, `FishComponent` and
+    /// `GoldfishComponent`; it starts out in no tank.
+    ///
+    /// # Returns
+    ///
+    /// Returns the UUID of the created goldfish for future reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name exceeds the configured [`PetStateOptions`], or if the
+    /// [`IdGenerator`] returns an id already in use.
+    ///
+    /// [`PetStateOptions`]: crate::pet_module::PetStateOptions
+    /// [`IdGenerator`]: crate::pet_module::IdGenerator
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let bubbles = ps.create_goldfish("Bubbles", WaterType::Fresh, 7, true);
+    /// assert_eq!(ps.get_all_goldfish()[0].pet.uuid, bubbles);
+    /// assert!(ps.get_all_mammals().is_empty());
+    /// ```
+    pub fn create_goldfish(
+        &mut self,
+        name: impl Into<String>,
+        water_type: WaterType,
+        fin_count: u32,
+        is_fancy: bool,
+    ) -> String {
+        self.create_fish(
+            name.into(),
+            FishComponent {
+                water_type,
+                fin_count,
+                tank: None,
+            },
+            GoldfishComponent { is_fancy },
+        )
+    }
+
+    /// Adds a betta to the ECS world, creating an entity with three components.
+    ///
+    /// Betta counterpart of [`create_goldfish`](Self::create_goldfish).
+    ///
+    /// # Returns
+    ///
+    /// Returns the UUID of the created betta for future reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name exceeds the configured [`PetStateOptions`], or if the
+    /// [`IdGenerator`] returns an id already in use.
+    ///
+    /// [`PetStateOptions`]: crate::pet_module::PetStateOptions
+    /// [`IdGenerator`]: crate::pet_module::IdGenerator
+    pub fn create_betta(
+        &mut self,
+        name: impl Into<String>,
+        water_type: WaterType,
+        fin_count: u32,
+        is_male: bool,
+    ) -> String {
+        self.create_fish(
+            name.into(),
+            FishComponent {
+                water_type,
+                fin_count,
+                tank: None,
+            },
+            BettaComponent { is_male },
+        )
+    }
+
+    /// Retrieves all fish, goldfish and bettas interleaved in creation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// ps.create_betta("Siamese", WaterType::Fresh, 5, true);
+    /// ps.create_dog("Rex", "brown", "boxer", true, 10.0, 15);
+    /// ps.create_goldfish("Bubbles", WaterType::Fresh, 7, true);
+    ///
+    /// let fish = ps.get_all_fish();
+    /// let names: Vec<&str> = fish.iter().map(Fish::name).collect();
+    /// assert_eq!(names, ["Siamese", "Bubbles"]);
+    /// ```
+    pub fn get_all_fish(&self) -> Vec<Fish> {
+        self.fish_matching(|_| true)
+    }
+
+    /// Retrieves all goldfish in creation order.
+    pub fn get_all_goldfish(&self) -> Vec<Goldfish> {
+        self.fish_matching(|_| true)
+            .into_iter()
+            .filter_map(|fish| match fish {
+                Fish::Goldfish(goldfish) => Some(goldfish),
+                Fish::Betta(_) => None,
+            })
+            .collect()
+    }
+
+    /// Retrieves all bettas in creation order.
+    pub fn get_all_bettas(&self) -> Vec<Betta> {
+        self.fish_matching(|_| true)
+            .into_iter()
+            .filter_map(|fish| match fish {
+                Fish::Betta(betta) => Some(betta),
+                Fish::Goldfish(_) => None,
+            })
+            .collect()
+    }
+
+    /// Adds a tank holding `water_type` water and returns its UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`IdGenerator`] returns an id already in use by a pet,
+    /// shelter or tank.
+    ///
+    /// [`IdGenerator`]: crate::pet_module::IdGenerator
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let tank_id = ps.add_tank("Living Room", WaterType::Fresh);
+    ///
+    /// assert_eq!(ps.tanks()[0].uuid, tank_id);
+    /// ```
+    pub fn add_tank(&mut self, name: impl Into<String>, water_type: WaterType) -> String {
+        let uuid = self.next_free_id().unwrap_or_else(|err| panic!("{err}"));
+
+        let entity = self
+            .ecs
+            .create_entity()
+            .with(TankComponent {
+                uuid: uuid.clone(),
+                name: name.into(),
+                water_type,
+            })
+            .build();
+        self.by_place_uuid.insert(uuid.clone(), entity);
+
+        uuid
+    }
+
+    /// Returns every tank.
+    pub fn tanks(&self) -> Vec<Tank> {
+        self.ecs
+            .read_storage::<TankComponent>()
+            .join()
+            .map(Tank::from)
+            .collect()
+    }
+
+    /// Puts the fish in the given tank, moving it if it already lives elsewhere.
+    ///
+    /// # Errors
+    ///
+    /// - [`PetError::PetNotFound`] if no fish has that UUID, including when
+    ///   the UUID belongs to a pet that isn't a fish
+    /// - [`PetError::TankNotFound`] if no tank has that UUID
+    /// - [`PetError::WaterTypeMismatch`] if the tank holds the wrong water
+    ///
+    /// A failed call leaves the fish where it was.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let bubbles = ps.create_goldfish("Bubbles", WaterType::Fresh, 7, true);
+    /// let reef = ps.add_tank("Reef", WaterType::Salt);
+    /// let bowl = ps.add_tank("Bowl", WaterType::Fresh);
+    ///
+    /// assert_eq!(
+    ///     ps.put_in_tank(&bubbles, &reef),
+    ///     Err(PetError::WaterTypeMismatch { fish: bubbles.clone(), tank: reef })
+    /// );
+    /// assert_eq!(ps.put_in_tank(&bubbles, &bowl), Ok(()));
+    /// assert_eq!(ps.tank_of(&bubbles).unwrap().name, "Bowl");
+    /// ```
+    pub fn put_in_tank(&mut self, fish_uuid: &str, tank_uuid: &str) -> Result<(), PetError> {
+        let fish = self
+            .find_entity(fish_uuid)
+            .ok_or_else(|| PetError::PetNotFound(fish_uuid.to_string()))?;
+        let tank = self.find_tank(tank_uuid)?;
+
+        let tank_water = self
+            .ecs
+            .read_storage::<TankComponent>()
+            .get(tank)
+            .map(|t| t.water_type);
+        let mut fetched_fish = self.ecs.write_storage::<FishComponent>();
+        let fish_component = fetched_fish
+            .get_mut(fish)
+            .ok_or_else(|| PetError::PetNotFound(fish_uuid.to_string()))?;
+        if tank_water != Some(fish_component.water_type) {
+            return Err(PetError::WaterTypeMismatch {
+                fish: fish_uuid.to_string(),
+                tank: tank_uuid.to_string(),
+            });
+        }
+
+        fish_component.tank = Some(tank);
+        Ok(())
+    }
+
+    /// Retrieves the fish living in the given tank, in creation order.
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet
+    /// INNER JOIN Fish ON Pet.id = Fish.pet_id
+    /// WHERE Fish.tank_id = ?
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::TankNotFound`] if no tank has that UUID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let bowl = ps.add_tank("Bowl", WaterType::Fresh);
+    /// let bubbles = ps.create_goldfish("Bubbles", WaterType::Fresh, 7, true);
+    /// let siamese = ps.create_betta("Siamese", WaterType::Fresh, 5, true);
+    /// ps.create_goldfish("Goldie", WaterType::Fresh, 7, false);
+    /// ps.put_in_tank(&bubbles, &bowl).unwrap();
+    /// ps.put_in_tank(&siamese, &bowl).unwrap();
+    ///
+    /// let in_bowl = ps.get_fish_in_tank(&bowl).unwrap();
+    /// assert_eq!(in_bowl.len(), 2);
+    /// assert!(matches!(in_bowl[1], Fish::Betta(_)));
+    /// ```
+    pub fn get_fish_in_tank(&self, tank_uuid: &str) -> Result<Vec<Fish>, PetError> {
+        let tank = self.find_tank(tank_uuid)?;
+        Ok(self.fish_matching(|fish| fish.tank == Some(tank)))
+    }
+
+    /// Returns the tank the fish lives in, or `None` if the fish is in no
+    /// tank or doesn't exist.
+    pub fn tank_of(&self, fish_uuid: &str) -> Option<Tank> {
+        let fish = self.find_entity(fish_uuid)?;
+        let tank = self.ecs.read_storage::<FishComponent>().get(fish)?.tank?;
+        self.ecs
+            .read_storage::<TankComponent>()
+            .get(tank)
+            .map(Tank::from)
+    }
+
+    /// Looks up a tank entity by UUID.
+    pub(super) fn find_tank(&self, uuid: &str) -> Result<Entity, PetError> {
+        let fetched_tanks = self.ecs.read_storage::<TankComponent>();
+
+        self.by_place_uuid
+            .get(uuid)
+            .copied()
+            .filter(|&entity| fetched_tanks.contains(entity))
+            .ok_or_else(|| PetError::TankNotFound(uuid.to_string()))
+    }
+
+    /// Builds a fish entity with the given leaf component.
+    fn create_fish<C: Component + Send + Sync>(
+        &mut self,
+        name: String,
+        fish: FishComponent,
+        leaf: C,
+    ) -> String {
+        if let Err(err) = self.check_new_pet(&name, None) {
+            panic!("{err}");
+        }
+        let uuid = self.next_free_id().unwrap_or_else(|err| panic!("{err}"));
+        let sequence = self.next_sequence();
+
+        let entity = self
+            .ecs
+            .create_entity()
+            .with(PetComponent {
+                uuid: uuid.clone(),
+                name: name.clone(),
+            })
+            .with(fish)
+            .with(leaf)
+            .with(sequence)
+            .build();
+        self.by_uuid.insert(uuid.clone(), entity);
+        index_name(&mut self.by_name, name, entity);
+
+        uuid
+    }
+
+    /// Visible fish whose `FishComponent` satisfies `predicate`, in creation order.
+    fn fish_matching(&self, predicate: impl Fn(&FishComponent) -> bool) -> Vec<Fish> {
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_fish = self.ecs.read_storage::<FishComponent>();
+        let fetched_goldfish = self.ecs.read_storage::<GoldfishComponent>();
+        let fetched_bettas = self.ecs.read_storage::<BettaComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_pets,
+            &fetched_fish,
+            fetched_goldfish.maybe(),
+            fetched_bettas.maybe(),
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .filter(|(_, fish, _, _, _, _)| predicate(fish))
+            .collect();
+        rows.sort_by_key(|(_, _, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        rows.into_iter()
+            .filter_map(
+                |(pet, fish, goldfish, betta, _, _)| match (goldfish, betta) {
+                    (Some(goldfish), _) => Some(Fish::Goldfish(Goldfish {
+                        pet: pet.into(),
+                        fish: fish.into(),
+                        goldfish_specific: goldfish.into(),
+                    })),
+                    (None, Some(betta)) => Some(Fish::Betta(Betta {
+                        pet: pet.into(),
+                        fish: fish.into(),
+                        betta_specific: betta.into(),
+                    })),
+                    (None, None) => None,
+                },
+            )
+            .collect()
+    }
+}
@@ -0,0 +1,190 @@
+//! Insurance policies associated with pets.
+//!
+//! Policies are separate entities rather than components on the pet,
+//! because a pet can hold many policies over time. Each policy entity
+//! stores the pet's `Entity` as a foreign key.
+//!
+//! # Component Mapping
+//!
+//! ```text
+//! PolicyComponent → Policy table (id, pet_id FK, provider, policy_number,
+//!                                 premium, coverage_start, coverage_end)
+//! ```
+
+use super::{PetComponent, PetState};
+use crate::pet_module::{Date, InsurancePolicy};
+use specs::prelude::*;
+use specs_derive::Component;
+
+/// Component representing the Policy table.
+///
+/// Maps to: `Policy` table with a foreign key (`pet`) to the Pet table.
+#[derive(Component, Debug, Clone)]
+#[storage(DenseVecStorage)]
+pub(super) struct PolicyComponent {
+    pet: Entity,
+    policy: InsurancePolicy,
+}
+
+impl PetState {
+    /// Attaches an insurance policy to the pet with the given UUID.
+    ///
+    /// Creates a new policy row referencing the pet. A pet may hold any
+    /// number of policies, e.g. consecutive yearly renewals.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the pet exists and the policy was attached.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// INSERT INTO Policy (id, pet_id, provider, policy_number, premium,
+    ///                     coverage_start, coverage_end)
+    ///   VALUES (uuid2, uuid, 'PetCare', 'PC-001', 29.90, '2024-01-01', '2024-12-31');
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// let policy = InsurancePolicy {
+    ///     provider: "PetCare".to_string(),
+    ///     policy_number: "PC-001".to_string(),
+    ///     premium: 29.90,
+    ///     coverage_start: Date::new(2024, 1, 1),
+    ///     coverage_end: Date::new(2024, 12, 31),
+    /// };
+    ///
+    /// assert!(ps.attach_policy(&rex, policy.clone()));
+    /// assert!(!ps.attach_policy("missing", policy));
+    /// assert_eq!(ps.policies(&rex).len(), 1);
+    /// ```
+    pub fn attach_policy(&mut self, pet_uuid: &str, policy: InsurancePolicy) -> bool {
+        let Some(pet) = self.find_entity(pet_uuid) else {
+            return false;
+        };
+
+        self.ecs
+            .create_entity()
+            .with(PolicyComponent { pet, policy })
+            .build();
+        true
+    }
+
+    /// Returns every policy attached to the pet, ordered by coverage start.
+    pub fn policies(&self, pet_uuid: &str) -> Vec<InsurancePolicy> {
+        let Some(pet) = self.find_entity(pet_uuid) else {
+            return Vec::new();
+        };
+
+        let fetched_policies = self.ecs.read_storage::<PolicyComponent>();
+        let mut policies: Vec<InsurancePolicy> = fetched_policies
+            .join()
+            .filter(|row| row.pet == pet)
+            .map(|row| row.policy.clone())
+            .collect();
+        policies.sort_by_key(|policy| policy.coverage_start);
+        policies
+    }
+
+    /// Returns the policy covering the pet on `as_of`, if any.
+    ///
+    /// When coverage periods overlap, the policy that started most recently wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// for (year, number) in [(2023, "PC-001"), (2024, "PC-002")] {
+    ///     ps.attach_policy(&rex, InsurancePolicy {
+    ///         provider: "PetCare".to_string(),
+    ///         policy_number: number.to_string(),
+    ///         premium: 29.90,
+    ///         coverage_start: Date::new(year, 1, 1),
+    ///         coverage_end: Date::new(year, 12, 31),
+    ///     });
+    /// }
+    ///
+    /// let active = ps.active_policy(&rex, Date::new(2024, 7, 1)).unwrap();
+    /// assert_eq!(active.policy_number, "PC-002");
+    /// assert!(ps.active_policy(&rex, Date::new(2025, 1, 1)).is_none());
+    /// ```
+    pub fn active_policy(&self, pet_uuid: &str, as_of: Date) -> Option<InsurancePolicy> {
+        self.policies(pet_uuid)
+            .into_iter()
+            .rfind(|policy| policy.covers(as_of))
+    }
+
+    /// Returns `(pet uuid, policy)` for every policy ending within `from..=to`.
+    ///
+    /// Useful for renewal reminders. Results are ordered by coverage end.
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT Pet.id, Policy.* FROM Policy
+    /// INNER JOIN Pet ON Policy.pet_id = Pet.id
+    /// WHERE Policy.coverage_end BETWEEN :from AND :to
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// ps.attach_policy(&rex, InsurancePolicy {
+    ///     provider: "PetCare".to_string(),
+    ///     policy_number: "PC-001".to_string(),
+    ///     premium: 29.90,
+    ///     coverage_start: Date::new(2024, 1, 1),
+    ///     coverage_end: Date::new(2024, 12, 31),
+    /// });
+    ///
+    /// let expiring = ps.policies_expiring(Date::new(2024, 12, 1), Date::new(2024, 12, 31));
+    /// assert_eq!(expiring.len(), 1);
+    /// assert_eq!(expiring[0].0, rex);
+    /// ```
+    pub fn policies_expiring(&self, from: Date, to: Date) -> Vec<(String, InsurancePolicy)> {
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_policies = self.ecs.read_storage::<PolicyComponent>();
+
+        let mut expiring: Vec<(String, InsurancePolicy)> = fetched_policies
+            .join()
+            .filter(|row| (from..=to).contains(&row.policy.coverage_end))
+            .filter_map(|row| {
+                let pet = fetched_pets.get(row.pet)?;
+                Some((pet.uuid.clone(), row.policy.clone()))
+            })
+            .collect();
+        expiring.sort_by_key(|(_, policy)| policy.coverage_end);
+        expiring
+    }
+
+    /// Deletes every policy row referencing `pet` (`ON DELETE CASCADE`).
+    pub(super) fn delete_policies_of(&mut self, pet: Entity) {
+        let policies: Vec<Entity> = {
+            let entities = self.ecs.entities();
+            let fetched_policies = self.ecs.read_storage::<PolicyComponent>();
+
+            (&entities, &fetched_policies)
+                .join()
+                .filter(|(_, row)| row.pet == pet)
+                .map(|(entity, _)| entity)
+                .collect()
+        };
+
+        // Entities were just read from the world, so they're all alive
+        let _ = self.ecs.delete_entities(&policies);
+    }
+}
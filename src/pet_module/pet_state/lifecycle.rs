@@ -0,0 +1,205 @@
+//! Pet lifecycle (alive/deceased) tracking.
+//!
+//! Lifecycle is domain state, not record management: a deceased pet keeps
+//! all of its rows, but collection queries leave it out by default.
+//!
+//! # Component Mapping
+//!
+//! ```text
+//! LifecycleComponent → PetLifecycle table (pet_id, status, date)
+//! ```
+//!
+//! Only pets whose status changed carry the component; a missing row means
+//! the pet is alive, like a nullable `deceased_on` column.
+
+use super::{PetComponent, PetState};
+use crate::pet_module::{Date, LifecycleStats, LifecycleStatus};
+use specs::prelude::*;
+use specs_derive::Component;
+
+/// Component representing the PetLifecycle table.
+///
+/// Maps to: `PetLifecycle` table with columns (pet_id, status, date)
+///
+/// Sparse (most pets are alive), hence `DenseVecStorage`.
+#[derive(Component, Debug, Clone)]
+#[storage(DenseVecStorage)]
+pub(super) struct LifecycleComponent {
+    status: LifecycleStatus,
+    date: Date,
+}
+
+impl PetState {
+    /// Records that the pet with the given UUID died on `date`.
+    ///
+    /// The pet's data is kept, but collection queries (`get_all_*`,
+    /// `collect_*_into`, `aggregate`, …) stop returning it unless
+    /// [`include_deceased`](Self::include_deceased) is enabled.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the pet exists, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// let (mut ps, _) = Dog::create(ps, "Fido", "white", "poodle", true, 7.0, 20);
+    ///
+    /// assert!(ps.mark_deceased(&rex, Date::new(2024, 3, 1)));
+    /// assert_eq!(ps.get_all_dogs().len(), 1);
+    /// assert_eq!(ps.lifecycle_status(&rex), Some(LifecycleStatus::Deceased));
+    /// ```
+    pub fn mark_deceased(&mut self, uuid: &str, date: Date) -> bool {
+        let Some(entity) = self.find_entity(uuid) else {
+            return false;
+        };
+
+        self.ecs
+            .write_storage::<LifecycleComponent>()
+            .insert(
+                entity,
+                LifecycleComponent {
+                    status: LifecycleStatus::Deceased,
+                    date,
+                },
+            )
+            .is_ok()
+    }
+
+    /// Returns the lifecycle status of the pet, or `None` if it doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (ps, id) = Cat::create(ps, "Tom", "gray", "tabby", true, false, true);
+    ///
+    /// assert_eq!(ps.lifecycle_status(&id), Some(LifecycleStatus::Alive));
+    /// assert_eq!(ps.lifecycle_status("missing"), None);
+    /// ```
+    pub fn lifecycle_status(&self, uuid: &str) -> Option<LifecycleStatus> {
+        let entity = self.find_entity(uuid)?;
+        let status = self
+            .ecs
+            .read_storage::<LifecycleComponent>()
+            .get(entity)
+            .map_or(LifecycleStatus::Alive, |lifecycle| lifecycle.status);
+        Some(status)
+    }
+
+    /// Sets whether collection queries include deceased pets (default `false`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, id) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// ps.mark_deceased(&id, Date::new(2024, 3, 1));
+    /// assert!(ps.get_all_mammals().is_empty());
+    ///
+    /// ps.include_deceased(true);
+    /// assert_eq!(ps.get_all_mammals().len(), 1);
+    /// ```
+    pub fn include_deceased(&mut self, include: bool) {
+        self.include_deceased = include;
+    }
+
+    /// Counts living and deceased pets and the range of recorded death dates.
+    ///
+    /// Always covers every pet, regardless of
+    /// [`include_deceased`](Self::include_deceased).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let ids: Vec<String> = ps.get_all_cats().into_iter().map(|c| c.pet.uuid).collect();
+    /// ps.mark_deceased(&ids[0], Date::new(2021, 6, 30));
+    /// ps.mark_deceased(&ids[1], Date::new(2019, 1, 15));
+    ///
+    /// let stats = ps.lifecycle_stats();
+    /// assert_eq!((stats.alive, stats.deceased), (3, 2));
+    /// assert_eq!(stats.first_death, Some(Date::new(2019, 1, 15)));
+    /// assert_eq!(stats.last_death, Some(Date::new(2021, 6, 30)));
+    ///
+    /// // Only dogs are left alive
+    /// assert_eq!(stats.min_typical_lifespan_years, Some(10));
+    /// assert_eq!(stats.max_typical_lifespan_years, Some(13));
+    /// ```
+    pub fn lifecycle_stats(&self) -> LifecycleStats {
+        let entities = self.ecs.entities();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_lifecycles = self.ecs.read_storage::<LifecycleComponent>();
+        let species_of = self.species_classifier();
+
+        (&entities, &fetched_pets, fetched_lifecycles.maybe())
+            .join()
+            .fold(
+                LifecycleStats::default(),
+                |mut stats, (entity, _, lifecycle)| {
+                    match lifecycle {
+                        Some(LifecycleComponent {
+                            status: LifecycleStatus::Deceased,
+                            date,
+                        }) => {
+                            stats.deceased += 1;
+                            stats.first_death =
+                                Some(stats.first_death.map_or(*date, |first| first.min(*date)));
+                            stats.last_death =
+                                Some(stats.last_death.map_or(*date, |last| last.max(*date)));
+                        }
+                        _ => {
+                            stats.alive += 1;
+                            if let Some(species) = species_of(entity) {
+                                let lifespan = species.typical_lifespan_years();
+                                stats.min_typical_lifespan_years = Some(
+                                    stats
+                                        .min_typical_lifespan_years
+                                        .map_or(*lifespan.start(), |min| {
+                                            min.min(*lifespan.start())
+                                        }),
+                                );
+                                stats.max_typical_lifespan_years = Some(
+                                    stats
+                                        .max_typical_lifespan_years
+                                        .map_or(*lifespan.end(), |max| max.max(*lifespan.end())),
+                                );
+                            }
+                        }
+                    }
+                    stats
+                },
+            )
+    }
+
+    /// Returns `true` if `entity` is marked deceased.
+    pub(super) fn is_deceased(&self, entity: Entity) -> bool {
+        self.ecs
+            .read_storage::<LifecycleComponent>()
+            .get(entity)
+            .is_some_and(|lifecycle| lifecycle.status == LifecycleStatus::Deceased)
+    }
+
+    /// Adds every deceased pet's entity to `mask`.
+    pub(super) fn add_deceased_to(&self, mask: &mut BitSet) {
+        let entities = self.ecs.entities();
+        let fetched_lifecycles = self.ecs.read_storage::<LifecycleComponent>();
+
+        for (entity, lifecycle) in (&entities, &fetched_lifecycles).join() {
+            if lifecycle.status == LifecycleStatus::Deceased {
+                mask.add(entity.id());
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Marker (mixin) components that can be attached to any pet.
+//!
+//! Single inheritance forces every optional trait into the hierarchy: a
+//! `trained` flag on `Pet` that most rows leave false, or a `TrainedDog`
+//! subclass that can't also be a `VaccinatedDog`. With ECS each trait is its
+//! own zero-sized component, attached to whichever entities have it and
+//! joined like any other table.
+//!
+//! # Component Mapping
+//!
+//! ```text
+//! TrainedComponent    → Trained table (pet_id)
+//! VaccinatedComponent → Vaccinated table (pet_id)
+//! AdoptedComponent    → Adopted table (pet_id)
+//! ```
+//!
+//! Each table holds just the ids of the pets with that trait; presence
+//! means the flag is set.
+
+use super::{DogComponent, MammalComponent, PetComponent, PetState, SequenceComponent};
+use crate::pet_module::{Dog, Mammal, Marker, PetError};
+use specs::prelude::*;
+use specs_derive::Component;
+
+/// Marker component flagging a trained pet.
+#[derive(Component, Debug, Clone, Default)]
+#[storage(NullStorage)]
+pub(super) struct TrainedComponent;
+
+/// Marker component flagging a vaccinated pet.
+#[derive(Component, Debug, Clone, Default)]
+#[storage(NullStorage)]
+pub(super) struct VaccinatedComponent;
+
+/// Marker component flagging an adopted pet.
+#[derive(Component, Debug, Clone, Default)]
+#[storage(NullStorage)]
+pub(super) struct AdoptedComponent;
+
+impl PetState {
+    /// Attaches `marker` to the pet with the given UUID.
+    ///
+    /// Attaching a marker the pet already has is a no-op.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// INSERT INTO Trained (pet_id) VALUES (uuid) ON CONFLICT DO NOTHING;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no pet has that UUID.
+    pub fn attach_marker(&mut self, uuid: &str, marker: Marker) -> Result<(), PetError> {
+        let entity = self
+            .find_entity(uuid)
+            .ok_or_else(|| PetError::PetNotFound(uuid.to_string()))?;
+        match marker {
+            Marker::Trained => self.insert_marker(entity, TrainedComponent),
+            Marker::Vaccinated => self.insert_marker(entity, VaccinatedComponent),
+            Marker::Adopted => self.insert_marker(entity, AdoptedComponent),
+        }
+        Ok(())
+    }
+
+    /// Detaches `marker` from the pet with the given UUID.
+    ///
+    /// Detaching a marker the pet doesn't have is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no pet has that UUID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, tom) = Cat::create(ps, "Tom", "gray", "tabby", true, false, true);
+    ///
+    /// ps.attach_marker(&tom, Marker::Adopted).unwrap();
+    /// ps.detach_marker(&tom, Marker::Adopted).unwrap();
+    /// assert!(!ps.has_marker(&tom, Marker::Adopted));
+    /// assert!(ps.detach_marker("missing", Marker::Adopted).is_err());
+    /// ```
+    pub fn detach_marker(&mut self, uuid: &str, marker: Marker) -> Result<(), PetError> {
+        let entity = self
+            .find_entity(uuid)
+            .ok_or_else(|| PetError::PetNotFound(uuid.to_string()))?;
+        match marker {
+            Marker::Trained => self.remove_marker::<TrainedComponent>(entity),
+            Marker::Vaccinated => self.remove_marker::<VaccinatedComponent>(entity),
+            Marker::Adopted => self.remove_marker::<AdoptedComponent>(entity),
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the pet exists and carries `marker`.
+    pub fn has_marker(&self, uuid: &str, marker: Marker) -> bool {
+        let Some(entity) = self.find_entity(uuid) else {
+            return false;
+        };
+        match marker {
+            Marker::Trained => self.ecs.read_storage::<TrainedComponent>().contains(entity),
+            Marker::Vaccinated => self
+                .ecs
+                .read_storage::<VaccinatedComponent>()
+                .contains(entity),
+            Marker::Adopted => self.ecs.read_storage::<AdoptedComponent>().contains(entity),
+        }
+    }
+
+    /// Gets dogs carrying the [`Marker::Trained`] marker.
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet
+    /// INNER JOIN Mammal ON Pet.id = Mammal.pet_id
+    /// INNER JOIN Dog ON Mammal.id = Dog.mammal_id
+    /// INNER JOIN Trained ON Pet.id = Trained.pet_id
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let dogs = ps.get_all_dogs();
+    /// ps.attach_marker(&dogs[1].pet.uuid, Marker::Trained).unwrap();
+    ///
+    /// assert_eq!(ps.get_trained_dogs(), [dogs[1].clone()]);
+    /// ```
+    pub fn get_trained_dogs(&self) -> Vec<Dog> {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_trained = self.ecs.read_storage::<TrainedComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_dogs,
+            &fetched_mammals,
+            &fetched_pets,
+            &fetched_trained,
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .collect();
+        rows.sort_by_key(|(_, _, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        rows.into_iter()
+            .map(|(dog, mammal, pet, _, _, _)| Dog {
+                pet: pet.into(),
+                mammal: mammal.into(),
+                dog_specific: dog.into(),
+            })
+            .collect()
+    }
+
+    /// Gets mammals without the [`Marker::Vaccinated`] marker, all leaf
+    /// types interleaved in creation order.
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet
+    /// INNER JOIN Mammal ON Pet.id = Mammal.pet_id
+    /// LEFT JOIN Vaccinated ON Pet.id = Vaccinated.pet_id
+    /// WHERE Vaccinated.pet_id IS NULL
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// for dog in ps.get_all_dogs() {
+    ///     ps.attach_marker(&dog.pet.uuid, Marker::Vaccinated).unwrap();
+    /// }
+    ///
+    /// let unvaccinated = ps.get_unvaccinated_mammals();
+    /// assert_eq!(unvaccinated.len(), 2);
+    /// assert!(unvaccinated.iter().all(Mammal::is_cat));
+    /// ```
+    pub fn get_unvaccinated_mammals(&self) -> Vec<Mammal> {
+        let fetched_vaccinated = self.ecs.read_storage::<VaccinatedComponent>();
+        let mut excluded = self.hidden_entities();
+        excluded |= fetched_vaccinated.mask();
+
+        let mut mammals = Vec::new();
+        self.visit_mammal_rows_excluding(excluded, |_, _, leaf, mammal, pet| {
+            mammals.push(leaf.compose(mammal, pet));
+        });
+        mammals
+    }
+
+    /// Inserts marker `C` on `entity`.
+    fn insert_marker<C: Component>(&mut self, entity: Entity, marker: C) {
+        // Entity came from find_entity, so it's alive
+        let _ = self.ecs.write_storage::<C>().insert(entity, marker);
+    }
+
+    /// Removes marker `C` from `entity`, if present.
+    fn remove_marker<C: Component>(&mut self, entity: Entity) {
+        self.ecs.write_storage::<C>().remove(entity);
+    }
+}
@@ -0,0 +1,209 @@
+//! Query objects and the fluent builder on top of them.
+//!
+//! [`PetQuery`] holds filter criteria as plain data, so parameters coming
+//! from an HTTP or CLI layer can be passed through as one value.
+//! [`MammalQuery`] builds the same value fluently. Either way the criteria
+//! run in a single pass over the `Pet`/`Mammal`/leaf join, composing only
+//! the rows that match.
+
+use super::{LeafRow, PetState, hair_color_matches};
+use crate::pet_module::{Mammal, Species};
+
+/// Query parameters for [`PetState::run_query`].
+///
+/// Every criterion is optional; unset criteria match everything. Build one
+/// with struct update syntax over `Default`.
+///
+/// This is a plain data type so it can cross layer boundaries. The crate
+/// does not depend on serde, so wire-format conversion is left to the
+/// calling layer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PetQuery {
+    /// Only this species.
+    pub species: Option<Species>,
+    /// Hair color, normalized like [`PetState::get_mammals_by_hair_color`].
+    pub hair_color: Option<String>,
+    /// Exact breed.
+    pub breed: Option<String>,
+    /// Substring of the name, ignoring case.
+    pub name_contains: Option<String>,
+    /// Maximum number of results.
+    pub limit: Option<usize>,
+    /// Number of matches to skip first.
+    pub offset: usize,
+}
+
+/// A mammal query under construction.
+///
+/// Obtained from [`PetState::query`]. Builds a [`PetQuery`] step by step.
+/// Hidden pets (deceased or soft-deleted) are excluded, like the
+/// `get_all_*` queries.
+#[derive(Clone)]
+pub struct MammalQuery<'a> {
+    ps: &'a PetState,
+    params: PetQuery,
+}
+
+impl MammalQuery<'_> {
+    /// Restricts the query to dogs.
+    pub fn dogs(mut self) -> Self {
+        self.params.species = Some(Species::Dog);
+        self
+    }
+
+    /// Restricts the query to cats.
+    pub fn cats(mut self) -> Self {
+        self.params.species = Some(Species::Cat);
+        self
+    }
+
+    /// Restricts the query to hamsters.
+    pub fn hamsters(mut self) -> Self {
+        self.params.species = Some(Species::Hamster);
+        self
+    }
+
+    /// Keeps mammals with this hair color, normalized like
+    /// [`PetState::get_mammals_by_hair_color`].
+    pub fn hair_color(mut self, hair_color: impl Into<String>) -> Self {
+        self.params.hair_color = Some(hair_color.into());
+        self
+    }
+
+    /// Keeps mammals with exactly this breed.
+    pub fn breed(mut self, breed: impl Into<String>) -> Self {
+        self.params.breed = Some(breed.into());
+        self
+    }
+
+    /// Stops after `limit` matches.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    /// Keeps mammals whose name contains `fragment`, ignoring case.
+    pub fn name_contains(mut self, fragment: impl Into<String>) -> Self {
+        self.params.name_contains = Some(fragment.into());
+        self
+    }
+
+    /// Skips the first `offset` matches.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.params.offset = offset;
+        self
+    }
+
+    /// The criteria collected so far.
+    pub fn params(&self) -> &PetQuery {
+        &self.params
+    }
+
+    /// Runs the query and composes the matching mammals.
+    ///
+    /// Results follow the order documented on
+    /// [`PetState::run_query`]: creation order, with `offset` and `limit`
+    /// applied to it.
+    pub fn collect(self) -> Vec<Mammal> {
+        self.ps.run_query(&self.params)
+    }
+}
+
+impl PetState {
+    /// Runs a [`PetQuery`] over all visible mammals.
+    ///
+    /// Mammals come in creation order, all leaf types interleaved like
+    /// [`get_all_mammals`](Self::get_all_mammals), and `offset` and `limit`
+    /// apply to that order. Only the returned rows are composed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::{PetQuery, Species};
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let query = PetQuery {
+    ///     species: Some(Species::Dog),
+    ///     name_contains: Some("o".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(ps.run_query(&query).len(), 2);
+    ///
+    /// let second_page = PetQuery { offset: 1, limit: Some(1), ..query };
+    /// assert_eq!(ps.run_query(&second_page).len(), 1);
+    /// ```
+    pub fn run_query(&self, query: &PetQuery) -> Vec<Mammal> {
+        let name_contains = query.name_contains.as_deref().map(str::to_lowercase);
+        let limit = query.limit.unwrap_or(usize::MAX);
+        let mut matches = Vec::new();
+        let mut skipped = 0;
+
+        self.visit_mammal_rows(|_, _, leaf, mammal, pet| {
+            let species = match leaf {
+                LeafRow::Dog(_) => Species::Dog,
+                LeafRow::Cat(_) => Species::Cat,
+                LeafRow::Hamster(_) => Species::Hamster,
+            };
+            let keep = query.species.is_none_or(|wanted| wanted == species)
+                && query
+                    .hair_color
+                    .as_deref()
+                    .is_none_or(|hair_color| hair_color_matches(&mammal.hair_color, hair_color))
+                && query
+                    .breed
+                    .as_deref()
+                    .is_none_or(|breed| mammal.breed.as_deref() == Some(breed))
+                && name_contains
+                    .as_deref()
+                    .is_none_or(|fragment| pet.name.to_lowercase().contains(fragment));
+            if !keep || matches.len() >= limit {
+                return;
+            }
+            if skipped < query.offset {
+                skipped += 1;
+            } else {
+                matches.push(leaf.compose(mammal, pet));
+            }
+        });
+        matches
+    }
+
+    /// Starts a fluent query over all visible mammals.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet p
+    /// JOIN Mammal m ON m.pet_id = p.id
+    /// JOIN Dog d ON d.mammal_id = m.pet_id
+    /// WHERE m.hair_color = 'blond' AND m.breed = 'schnauzer'
+    /// LIMIT 10;
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let dogs = ps
+    ///     .query()
+    ///     .dogs()
+    ///     .hair_color("blond")
+    ///     .breed("schnauzer")
+    ///     .limit(10)
+    ///     .collect();
+    /// assert_eq!(dogs.len(), 1);
+    /// assert_eq!(dogs[0].name(), "Sophie");
+    ///
+    /// assert_eq!(ps.query().hair_color("Blond").collect().len(), 3);
+    /// assert_eq!(ps.query().cats().limit(1).collect().len(), 1);
+    /// ```
+    pub fn query(&self) -> MammalQuery<'_> {
+        MammalQuery {
+            ps: self,
+            params: PetQuery::default(),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Embedded example dataset.
+//!
+//! A fixed, varied world for demos and doc examples: enough pets that
+//! queries, aggregates, and relationships all have something interesting
+//! to return. Everything except the generated UUIDs is deterministic.
+
+use super::PetState;
+use crate::pet_module::{Cat, Date, Dog, InsurancePolicy, SpeciesInfo};
+
+const DOG_NAMES: [&str; 30] = [
+    "Rex", "Buddy", "Max", "Bella", "Charlie", "Luna", "Cooper", "Daisy", "Rocky", "Molly", "Bear",
+    "Sadie", "Duke", "Maggie", "Tucker", "Bailey", "Zeus", "Rosie", "Milo", "Penny", "Oscar",
+    "Ruby", "Toby", "Stella", "Winston", "Lola", "Murphy", "Gracie", "Bruno", "Pepper",
+];
+
+const CAT_NAMES: [&str; 24] = [
+    "Tom", "Felix", "Simba", "Nala", "Oliver", "Cleo", "Leo", "Misty", "Jasper", "Willow",
+    "Smokey", "Mittens", "Tiger", "Chloe", "Shadow", "Ginger", "Pumpkin", "Salem", "Whiskers",
+    "Bagheera", "Pixel", "Mochi", "Biscuit", "Juniper",
+];
+
+const DOG_HAIR_COLORS: [&str; 6] = ["black", "brown", "golden", "white", "gray", "brindle"];
+const CAT_HAIR_COLORS: [&str; 6] = [
+    "black",
+    "white",
+    "orange",
+    "gray",
+    "calico",
+    "tortoiseshell",
+];
+
+impl PetState {
+    /// Creates a state pre-loaded with the embedded example dataset.
+    ///
+    /// | What      | Contents                                                        |
+    /// |-----------|-----------------------------------------------------------------|
+    /// | Dogs      | 30 across the default breeds; every 7th is mixed (`NULL` breed) |
+    /// | Cats      | 24, two of them hairless sphynxes, the rest the default breeds  |
+    /// | Shelters  | "Northside Rescue" (8 of 10 places), "Harbor Animal Shelter" (5 of 5) |
+    /// | Insurance | Every third dog, covered for 2024                               |
+    /// | Deceased  | The last dog and the last cat                                   |
+    ///
+    /// Deceased pets are hidden from collection queries by default, so 52
+    /// pets are visible out of 54.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::with_sample_data();
+    /// assert_eq!(ps.get_all_dogs().len(), 29);
+    /// assert_eq!(ps.get_all_cats().len(), 23);
+    /// assert_eq!(ps.shelters().len(), 2);
+    ///
+    /// ps.include_deceased(true);
+    /// assert_eq!(ps.get_all_mammals().len(), 54);
+    /// ```
+    pub fn with_sample_data() -> Self {
+        let mut ps = PetState::new();
+        let northside = ps.add_shelter("Northside Rescue", 10);
+        let harbor = ps.add_shelter("Harbor Animal Shelter", 5);
+
+        for (i, name) in DOG_NAMES.into_iter().enumerate() {
+            let breed = (i % 7 != 6).then(|| Dog::DEFAULT_BREEDS[i % Dog::DEFAULT_BREEDS.len()]);
+            let uuid = ps.create_dog(
+                name,
+                DOG_HAIR_COLORS[i % DOG_HAIR_COLORS.len()],
+                breed.map(str::to_string),
+                true,
+                4.0 + (i * 7 % 20) as f64 / 2.0,
+                (i * 13 % 40) as i32,
+            );
+
+            if i % 4 == 1 {
+                ps.house(&uuid, &northside)
+                    .expect("sample shelter has room");
+            }
+            if i % 3 == 0 {
+                ps.attach_policy(
+                    &uuid,
+                    InsurancePolicy {
+                        provider: "PetSure".to_string(),
+                        policy_number: format!("PS-{:04}", 1000 + i),
+                        premium: 18.5 + (i % 5) as f64 * 4.0,
+                        coverage_start: Date::new(2024, 1, 1),
+                        coverage_end: Date::new(2024, 12, 31),
+                    },
+                );
+            }
+            if i == DOG_NAMES.len() - 1 {
+                ps.mark_deceased(&uuid, Date::new(2024, 8, 14));
+            }
+        }
+
+        for (i, name) in CAT_NAMES.into_iter().enumerate() {
+            let hairless = i % 12 == 5;
+            let breed = if hairless {
+                "sphynx"
+            } else {
+                Cat::DEFAULT_BREEDS[i % Cat::DEFAULT_BREEDS.len()]
+            };
+            let uuid = ps.create_cat(
+                name,
+                CAT_HAIR_COLORS[i % CAT_HAIR_COLORS.len()],
+                breed,
+                !hairless,
+                i % 4 == 0,
+                i % 3 != 2,
+            );
+
+            if i % 5 == 0 {
+                ps.house(&uuid, &harbor).expect("sample shelter has room");
+            }
+            if i == CAT_NAMES.len() - 1 {
+                ps.mark_deceased(&uuid, Date::new(2023, 11, 2));
+            }
+        }
+
+        ps
+    }
+}
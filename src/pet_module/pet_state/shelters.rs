@@ -0,0 +1,546 @@
+//! Shelters and the pets housed in them.
+//!
+//! Demonstrates an aggregate invariant enforced by the gateway: a shelter
+//! never houses more pets than its capacity. SQL can't express that as a
+//! simple constraint, so the check lives in [`PetState::house`].
+//!
+//! Each shelter also keeps a FIFO waitlist. The queue is an ordered
+//! collection (`VecDeque`) stored inside the shelter's component, next to
+//! the unordered ECS storages.
+//!
+//! # Component Mapping
+//!
+//! ```text
+//! ShelterComponent → Shelter table (id, name, capacity)
+//!                    + ShelterWaitlist table (shelter_id, position, pet_id)
+//! HousedComponent  → Pet.shelter_id foreign key column (nullable)
+//! ```
+
+use super::{PetComponent, PetState};
+use crate::pet_module::{CascadePolicy, Occupancy, PetError, Shelter, TransferSummary};
+use specs::prelude::*;
+use specs_derive::Component;
+use std::collections::VecDeque;
+
+/// Component representing the Shelter table.
+///
+/// Maps to: `Shelter` table with columns (id, name, capacity)
+#[derive(Component, Debug, Clone)]
+#[storage(DenseVecStorage)]
+pub(super) struct ShelterComponent {
+    uuid: String,
+    name: String,
+    capacity: usize,
+    /// Pets waiting for a place, front of the queue first.
+    waitlist: VecDeque<Entity>,
+}
+
+impl From<&ShelterComponent> for Shelter {
+    fn from(comp: &ShelterComponent) -> Self {
+        Self {
+            uuid: comp.uuid.clone(),
+            name: comp.name.clone(),
+            capacity: comp.capacity,
+        }
+    }
+}
+
+/// Component linking a pet to the shelter housing it.
+///
+/// Maps to: nullable `Pet.shelter_id` column; absent means not housed.
+#[derive(Component, Debug, Clone)]
+#[storage(DenseVecStorage)]
+pub(super) struct HousedComponent {
+    pub(super) shelter: Entity,
+}
+
+impl PetState {
+    /// Adds a shelter with room for `capacity` pets and returns its UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`IdGenerator`] returns an id already in use by a pet,
+    /// shelter or tank.
+    ///
+    /// [`IdGenerator`]: crate::pet_module::IdGenerator
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let shelter_id = ps.add_shelter("Happy Paws", 10);
+    ///
+    /// assert_eq!(ps.shelters()[0].uuid, shelter_id);
+    ///
+    /// // Pets, shelters and tanks share one id space
+    /// let mut ps = PetState::builder().id_generator(|| "same".to_string()).build();
+    /// ps.add_shelter("Riverside", 5);
+    /// let rex = DogInput {
+    ///     name: "Rex".to_string(),
+    ///     hair_color: "brown".to_string(),
+    ///     breed: None,
+    ///     has_hair: true,
+    ///     tail_length: 10.0,
+    ///     num_commands_known: 15,
+    /// };
+    /// assert_eq!(ps.try_add_dog(rex), Err(PetError::DuplicateId("same".into())));
+    /// ```
+    pub fn add_shelter(&mut self, name: impl Into<String>, capacity: usize) -> String {
+        let uuid = self.next_free_id().unwrap_or_else(|err| panic!("{err}"));
+
+        let entity = self
+            .ecs
+            .create_entity()
+            .with(ShelterComponent {
+                uuid: uuid.clone(),
+                name: name.into(),
+                capacity,
+                waitlist: VecDeque::new(),
+            })
+            .build();
+        self.by_place_uuid.insert(uuid.clone(), entity);
+
+        uuid
+    }
+
+    /// Returns every shelter.
+    pub fn shelters(&self) -> Vec<Shelter> {
+        self.ecs
+            .read_storage::<ShelterComponent>()
+            .join()
+            .map(Shelter::from)
+            .collect()
+    }
+
+    /// Deletes a shelter, applying `policy` to the pets housed there.
+    ///
+    /// The shelter's waitlist is dropped with it; waiting pets are not
+    /// housed anywhere, so they're unaffected.
+    ///
+    /// # Returns
+    ///
+    /// Returns how many housed pets were deleted ([`CascadePolicy::Cascade`])
+    /// or unhoused ([`CascadePolicy::Nullify`]).
+    ///
+    /// # Errors
+    ///
+    /// - [`PetError::ShelterNotFound`] if no shelter has that UUID
+    /// - [`PetError::DeleteRestricted`] under [`CascadePolicy::Restrict`] when
+    ///   the shelter still has residents; nothing is deleted
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let shelter_id = ps.add_shelter("Riverside", 5);
+    /// for cat in ps.get_all_cats() {
+    ///     ps.house(&cat.pet.uuid, &shelter_id).unwrap();
+    /// }
+    ///
+    /// assert!(matches!(
+    ///     ps.delete_shelter(&shelter_id, CascadePolicy::Restrict),
+    ///     Err(PetError::DeleteRestricted { residents: 2, .. })
+    /// ));
+    ///
+    /// assert_eq!(ps.delete_shelter(&shelter_id, CascadePolicy::Nullify), Ok(2));
+    /// assert_eq!(ps.get_all_cats().len(), 2);
+    /// assert!(ps.shelters().is_empty());
+    /// ```
+    pub fn delete_shelter(
+        &mut self,
+        shelter_uuid: &str,
+        policy: CascadePolicy,
+    ) -> Result<usize, PetError> {
+        let shelter = self.find_shelter(shelter_uuid)?;
+
+        let residents: Vec<Entity> = {
+            let entities = self.ecs.entities();
+            let fetched_housed = self.ecs.read_storage::<HousedComponent>();
+            (&entities, &fetched_housed)
+                .join()
+                .filter(|(_, housed)| housed.shelter == shelter)
+                .map(|(entity, _)| entity)
+                .collect()
+        };
+
+        match policy {
+            CascadePolicy::Restrict if !residents.is_empty() => {
+                return Err(PetError::DeleteRestricted {
+                    shelter: shelter_uuid.to_string(),
+                    residents: residents.len(),
+                });
+            }
+            CascadePolicy::Restrict => {}
+            CascadePolicy::Nullify => {
+                let mut fetched_housed = self.ecs.write_storage::<HousedComponent>();
+                for &pet in &residents {
+                    fetched_housed.remove(pet);
+                }
+            }
+            CascadePolicy::Cascade => {
+                for &pet in &residents {
+                    self.delete_pet_entity(pet);
+                }
+            }
+        }
+
+        // Entity came from find_shelter, so it's alive
+        let _ = self.ecs.delete_entity(shelter);
+        self.by_place_uuid.remove(shelter_uuid);
+        Ok(residents.len())
+    }
+
+    /// Houses the pet in the given shelter, moving it if already housed elsewhere.
+    ///
+    /// # Errors
+    ///
+    /// - [`PetError::PetNotFound`] / [`PetError::ShelterNotFound`] for unknown ids
+    /// - [`PetError::CapacityExceeded`] if the shelter is already full
+    ///
+    /// A failed call leaves the pet where it was.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let shelter_id = ps.add_shelter("Tiny Shelter", 1);
+    /// let dogs = ps.get_all_dogs();
+    ///
+    /// assert_eq!(ps.house(&dogs[0].pet.uuid, &shelter_id), Ok(()));
+    /// assert_eq!(
+    ///     ps.house(&dogs[1].pet.uuid, &shelter_id),
+    ///     Err(PetError::CapacityExceeded { shelter: shelter_id.clone(), capacity: 1 })
+    /// );
+    /// ```
+    pub fn house(&mut self, pet_uuid: &str, shelter_uuid: &str) -> Result<(), PetError> {
+        let pet = self
+            .find_entity(pet_uuid)
+            .ok_or_else(|| PetError::PetNotFound(pet_uuid.to_string()))?;
+        let shelter = self.find_shelter(shelter_uuid)?;
+
+        let mut fetched_housed = self.ecs.write_storage::<HousedComponent>();
+        if fetched_housed
+            .get(pet)
+            .is_some_and(|housed| housed.shelter == shelter)
+        {
+            return Ok(());
+        }
+
+        let capacity = self
+            .ecs
+            .read_storage::<ShelterComponent>()
+            .get(shelter)
+            .map_or(0, |s| s.capacity);
+        let residents = fetched_housed
+            .join()
+            .filter(|housed| housed.shelter == shelter)
+            .count();
+        if residents >= capacity {
+            return Err(PetError::CapacityExceeded {
+                shelter: shelter_uuid.to_string(),
+                capacity,
+            });
+        }
+
+        // Entity came from find_entity, so it's alive
+        let _ = fetched_housed.insert(pet, HousedComponent { shelter });
+        Ok(())
+    }
+
+    /// Removes the pet from whatever shelter houses it (sets `shelter_id` to `NULL`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no pet has that UUID. Unhousing a
+    /// pet that isn't housed is not an error.
+    pub fn unhouse(&mut self, pet_uuid: &str) -> Result<(), PetError> {
+        let pet = self
+            .find_entity(pet_uuid)
+            .ok_or_else(|| PetError::PetNotFound(pet_uuid.to_string()))?;
+
+        self.ecs.write_storage::<HousedComponent>().remove(pet);
+        Ok(())
+    }
+
+    /// Adds the pet to the back of the shelter's waitlist.
+    ///
+    /// Enqueueing a pet that is already waiting for this shelter keeps its
+    /// original position.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] or [`PetError::ShelterNotFound`] for
+    /// unknown ids.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let shelter_id = ps.add_shelter("Tiny Shelter", 1);
+    /// let cats = ps.get_all_cats();
+    ///
+    /// ps.enqueue(&shelter_id, &cats[0].pet.uuid).unwrap();
+    /// ps.enqueue(&shelter_id, &cats[1].pet.uuid).unwrap();
+    ///
+    /// let occupancy = ps.occupancy(&shelter_id).unwrap();
+    /// assert_eq!(occupancy.waitlist.len(), 2);
+    /// assert_eq!(occupancy.waitlist[0].name, "Berlioz");
+    /// ```
+    pub fn enqueue(&mut self, shelter_uuid: &str, pet_uuid: &str) -> Result<(), PetError> {
+        let pet = self
+            .find_entity(pet_uuid)
+            .ok_or_else(|| PetError::PetNotFound(pet_uuid.to_string()))?;
+        let shelter = self.find_shelter(shelter_uuid)?;
+
+        let mut fetched_shelters = self.ecs.write_storage::<ShelterComponent>();
+        if let Some(row) = fetched_shelters.get_mut(shelter)
+            && !row.waitlist.contains(&pet)
+        {
+            row.waitlist.push_back(pet);
+        }
+        Ok(())
+    }
+
+    /// Houses the pet at the front of the shelter's waitlist.
+    ///
+    /// Pets deleted while waiting are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the UUID of the admitted pet, or `None` if nobody is waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::CapacityExceeded`] if the shelter is full; the
+    /// waitlist is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let shelter_id = ps.add_shelter("Tiny Shelter", 1);
+    /// let cats = ps.get_all_cats();
+    /// ps.enqueue(&shelter_id, &cats[0].pet.uuid).unwrap();
+    /// ps.enqueue(&shelter_id, &cats[1].pet.uuid).unwrap();
+    ///
+    /// assert_eq!(ps.admit_next(&shelter_id), Ok(Some(cats[0].pet.uuid.clone())));
+    /// assert!(ps.admit_next(&shelter_id).is_err());
+    ///
+    /// ps.unhouse(&cats[0].pet.uuid).unwrap();
+    /// assert_eq!(ps.admit_next(&shelter_id), Ok(Some(cats[1].pet.uuid.clone())));
+    /// assert_eq!(ps.admit_next(&shelter_id), Ok(None));
+    /// ```
+    pub fn admit_next(&mut self, shelter_uuid: &str) -> Result<Option<String>, PetError> {
+        let shelter = self.find_shelter(shelter_uuid)?;
+
+        loop {
+            let front = self
+                .ecs
+                .read_storage::<ShelterComponent>()
+                .get(shelter)
+                .and_then(|row| row.waitlist.front().copied());
+            let Some(pet) = front else {
+                return Ok(None);
+            };
+
+            let pet_uuid = self
+                .ecs
+                .read_storage::<PetComponent>()
+                .get(pet)
+                .map(|row| row.uuid.clone());
+            if let Some(pet_uuid) = &pet_uuid {
+                self.house(pet_uuid, shelter_uuid)?;
+            }
+
+            if let Some(row) = self
+                .ecs
+                .write_storage::<ShelterComponent>()
+                .get_mut(shelter)
+            {
+                row.waitlist.pop_front();
+            }
+            if pet_uuid.is_some() {
+                return Ok(pet_uuid);
+            }
+        }
+    }
+
+    /// Moves every resident and waiting pet from one shelter to another.
+    ///
+    /// The move is all-or-nothing: if the destination lacks room for every
+    /// resident, nothing moves. Waiting pets join the back of the
+    /// destination's waitlist in their original order.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// BEGIN;
+    /// UPDATE Pet SET shelter_id = :to WHERE shelter_id = :from;
+    /// UPDATE ShelterWaitlist SET shelter_id = :to WHERE shelter_id = :from;
+    /// COMMIT;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`PetError::ShelterNotFound`] if either shelter is unknown
+    /// - [`PetError::CapacityExceeded`] if the destination can't take everyone
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let flooded = ps.add_shelter("Riverside", 5);
+    /// let small = ps.add_shelter("Hilltop", 2);
+    /// let large = ps.add_shelter("Downtown", 10);
+    ///
+    /// for dog in ps.get_all_dogs() {
+    ///     ps.house(&dog.pet.uuid, &flooded).unwrap();
+    /// }
+    ///
+    /// assert!(ps.evacuate(&flooded, &small).is_err());
+    ///
+    /// let summary = ps.evacuate(&flooded, &large).unwrap();
+    /// assert_eq!(summary.moved, 3);
+    /// assert!(ps.occupancy(&flooded).unwrap().residents.is_empty());
+    /// ```
+    pub fn evacuate(
+        &mut self,
+        from_shelter: &str,
+        to_shelter: &str,
+    ) -> Result<TransferSummary, PetError> {
+        let from = self.find_shelter(from_shelter)?;
+        let to = self.find_shelter(to_shelter)?;
+        if from == to {
+            return Ok(TransferSummary::default());
+        }
+
+        let mut fetched_shelters = self.ecs.write_storage::<ShelterComponent>();
+        let mut fetched_housed = self.ecs.write_storage::<HousedComponent>();
+
+        let (mut leaving, mut present) = (0, 0);
+        for housed in fetched_housed.join() {
+            leaving += usize::from(housed.shelter == from);
+            present += usize::from(housed.shelter == to);
+        }
+        let capacity = fetched_shelters.get(to).map_or(0, |row| row.capacity);
+        if present + leaving > capacity {
+            return Err(PetError::CapacityExceeded {
+                shelter: to_shelter.to_string(),
+                capacity,
+            });
+        }
+
+        for housed in (&mut fetched_housed).join() {
+            if housed.shelter == from {
+                housed.shelter = to;
+            }
+        }
+
+        let waiting: Vec<Entity> = fetched_shelters
+            .get_mut(from)
+            .map(|row| row.waitlist.drain(..).collect())
+            .unwrap_or_default();
+        let mut requeued = 0;
+        if let Some(row) = fetched_shelters.get_mut(to) {
+            for pet in waiting {
+                if !row.waitlist.contains(&pet) {
+                    row.waitlist.push_back(pet);
+                    requeued += 1;
+                }
+            }
+        }
+
+        Ok(TransferSummary {
+            moved: leaving,
+            requeued,
+        })
+    }
+
+    /// Reports the capacity, current residents, and waitlist of a shelter.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::ShelterNotFound`] if no shelter has that UUID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let mut ps = PetState::new();
+    /// let shelter_id = ps.add_shelter("Happy Paws", 2);
+    /// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    /// ps.house(&rex, &shelter_id).unwrap();
+    ///
+    /// let occupancy = ps.occupancy(&shelter_id).unwrap();
+    /// assert_eq!(occupancy.residents[0].name, "Rex");
+    /// assert!(!occupancy.is_full());
+    /// ```
+    pub fn occupancy(&self, shelter_uuid: &str) -> Result<Occupancy, PetError> {
+        let shelter = self.find_shelter(shelter_uuid)?;
+        let fetched_shelters = self.ecs.read_storage::<ShelterComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_housed = self.ecs.read_storage::<HousedComponent>();
+
+        let residents = (&fetched_pets, &fetched_housed)
+            .join()
+            .filter(|(_, housed)| housed.shelter == shelter)
+            .map(|(pet, _)| pet.into())
+            .collect();
+
+        let row = fetched_shelters.get(shelter);
+        let waitlist = row
+            .into_iter()
+            .flat_map(|row| &row.waitlist)
+            .filter_map(|&pet| fetched_pets.get(pet))
+            .map(|pet| pet.into())
+            .collect();
+
+        Ok(Occupancy {
+            capacity: row.map_or(0, |row| row.capacity),
+            residents,
+            waitlist,
+        })
+    }
+
+    /// Counts waitlist entries across every shelter, skipping pets that no
+    /// longer exist, as [`occupancy`](Self::occupancy) does.
+    pub(super) fn waiting_count(&self) -> usize {
+        let fetched_shelters = self.ecs.read_storage::<ShelterComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+
+        fetched_shelters
+            .join()
+            .flat_map(|row| &row.waitlist)
+            .filter(|&&pet| fetched_pets.contains(pet))
+            .count()
+    }
+
+    /// Looks up the entity of the shelter with the given UUID.
+    pub(super) fn find_shelter(&self, uuid: &str) -> Result<Entity, PetError> {
+        let fetched_shelters = self.ecs.read_storage::<ShelterComponent>();
+
+        self.by_place_uuid
+            .get(uuid)
+            .copied()
+            .filter(|&entity| fetched_shelters.contains(entity))
+            .ok_or_else(|| PetError::ShelterNotFound(uuid.to_string()))
+    }
+}
@@ -0,0 +1,113 @@
+//! Logical (soft) deletion with restore.
+//!
+//! A soft-deleted pet keeps all of its rows and relationships but is left
+//! out of every collection query, like a `deleted` flag that every `SELECT`
+//! filters on. Unlike [`PetState::delete`] it can be undone.
+//!
+//! # Component Mapping
+//!
+//! ```text
+//! DeletedComponent → Pet.deleted (boolean flag)
+//! ```
+//!
+//! The component is a zero-sized marker: presence means deleted.
+
+use super::{DogComponent, MammalComponent, PetComponent, PetState};
+use crate::pet_module::{Dog, PetError};
+use specs::prelude::*;
+use specs_derive::Component;
+
+/// Marker component flagging a soft-deleted pet.
+#[derive(Component, Debug, Clone, Default)]
+#[storage(NullStorage)]
+pub(super) struct DeletedComponent;
+
+impl PetState {
+    /// Soft-deletes the pet with the given UUID.
+    ///
+    /// The pet stays in storage and can be brought back with
+    /// [`restore`](Self::restore), but collection queries no longer return it.
+    /// Soft-deleting an already deleted pet is a no-op.
+    ///
+    /// # Database Equivalent
+    ///
+    /// ```sql
+    /// UPDATE Pet SET deleted = TRUE WHERE id = uuid;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no pet has that UUID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    ///
+    /// let ps = PetState::new();
+    /// let (mut ps, rex) = Dog::create(ps, "Rex", "brown", "boxer", true, 10.0, 15);
+    ///
+    /// ps.soft_delete(&rex).unwrap();
+    /// assert!(ps.get_all_dogs().is_empty());
+    /// assert_eq!(ps.get_all_dogs_including_deleted().len(), 1);
+    ///
+    /// ps.restore(&rex).unwrap();
+    /// assert_eq!(ps.get_all_dogs().len(), 1);
+    /// ```
+    pub fn soft_delete(&mut self, uuid: &str) -> Result<(), PetError> {
+        let entity = self
+            .find_entity(uuid)
+            .ok_or_else(|| PetError::PetNotFound(uuid.to_string()))?;
+        // Inserting into a live entity cannot fail.
+        let _ = self
+            .ecs
+            .write_storage::<DeletedComponent>()
+            .insert(entity, DeletedComponent);
+        Ok(())
+    }
+
+    /// Undoes a [`soft_delete`](Self::soft_delete).
+    ///
+    /// Restoring a pet that is not deleted is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PetError::PetNotFound`] if no pet has that UUID.
+    pub fn restore(&mut self, uuid: &str) -> Result<(), PetError> {
+        let entity = self
+            .find_entity(uuid)
+            .ok_or_else(|| PetError::PetNotFound(uuid.to_string()))?;
+        self.ecs.write_storage::<DeletedComponent>().remove(entity);
+        Ok(())
+    }
+
+    /// Returns all dogs, soft-deleted ones included.
+    ///
+    /// Other visibility settings (such as
+    /// [`include_deceased`](Self::include_deceased)) still apply.
+    pub fn get_all_dogs_including_deleted(&self) -> Vec<Dog> {
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let hidden = self.hidden_by_settings();
+
+        (&fetched_dogs, &fetched_mammals, &fetched_pets, !&hidden)
+            .join()
+            .map(|(dog, mammal, pet, _)| Dog {
+                pet: pet.into(),
+                mammal: mammal.into(),
+                dog_specific: dog.into(),
+            })
+            .collect()
+    }
+
+    /// Returns `true` if `entity` is soft-deleted.
+    pub(super) fn is_soft_deleted(&self, entity: Entity) -> bool {
+        self.ecs.read_storage::<DeletedComponent>().contains(entity)
+    }
+
+    /// Adds every soft-deleted pet's entity to `mask`.
+    pub(super) fn add_soft_deleted_to(&self, mask: &mut BitSet) {
+        *mask |= self.ecs.read_storage::<DeletedComponent>().mask();
+    }
+}
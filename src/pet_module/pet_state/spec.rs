@@ -0,0 +1,174 @@
+//! Specification pattern over the gateway.
+//!
+//! A [`Spec`] is a predicate expressed as data: leaf criteria combined with
+//! `and`, `or` and `!`. [`PetState::find`] evaluates it against component
+//! rows inside the join, so only matching mammals are composed.
+
+use super::{LeafRow, MammalComponent, PetComponent, PetState, hair_color_matches};
+use crate::pet_module::Mammal;
+use std::ops::Not;
+
+/// A composable predicate over mammals.
+///
+/// Build leaves with the constructor functions and combine them with
+/// [`and`](Self::and), [`or`](Self::or) and the `!` operator.
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::pet_module::Spec;
+///
+/// let spec = Spec::hair_color("blond")
+///     .and(Spec::is_dog())
+///     .or(Spec::name_starts_with("S"));
+/// let not_cats = !Spec::is_cat();
+/// # let _ = (spec, not_cats);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Spec {
+    /// Matches every mammal.
+    Any,
+    /// Hair color equals the value, normalized like
+    /// [`PetState::get_mammals_by_hair_color`].
+    HairColor(String),
+    /// Breed equals the value exactly.
+    Breed(String),
+    /// The mammal is a dog.
+    IsDog,
+    /// The mammal is a cat.
+    IsCat,
+    /// The mammal is a hamster.
+    IsHamster,
+    /// Name starts with the value (case-sensitive).
+    NameStartsWith(String),
+    /// Both specifications match.
+    And(Box<Spec>, Box<Spec>),
+    /// Either specification matches.
+    Or(Box<Spec>, Box<Spec>),
+    /// The specification does not match.
+    Not(Box<Spec>),
+}
+
+impl Spec {
+    /// See [`Spec::HairColor`].
+    pub fn hair_color(hair_color: impl Into<String>) -> Self {
+        Spec::HairColor(hair_color.into())
+    }
+
+    /// See [`Spec::Breed`].
+    pub fn breed(breed: impl Into<String>) -> Self {
+        Spec::Breed(breed.into())
+    }
+
+    /// See [`Spec::IsDog`].
+    pub fn is_dog() -> Self {
+        Spec::IsDog
+    }
+
+    /// See [`Spec::IsCat`].
+    pub fn is_cat() -> Self {
+        Spec::IsCat
+    }
+
+    /// See [`Spec::IsHamster`].
+    pub fn is_hamster() -> Self {
+        Spec::IsHamster
+    }
+
+    /// See [`Spec::NameStartsWith`].
+    pub fn name_starts_with(prefix: impl Into<String>) -> Self {
+        Spec::NameStartsWith(prefix.into())
+    }
+
+    /// Matches when both `self` and `other` match.
+    pub fn and(self, other: Spec) -> Self {
+        Spec::And(Box::new(self), Box::new(other))
+    }
+
+    /// Matches when `self` or `other` matches.
+    pub fn or(self, other: Spec) -> Self {
+        Spec::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Evaluates the specification against one joined row.
+    fn matches(&self, leaf: &LeafRow, mammal: &MammalComponent, pet: &PetComponent) -> bool {
+        match self {
+            Spec::Any => true,
+            Spec::HairColor(hair_color) => hair_color_matches(&mammal.hair_color, hair_color),
+            Spec::Breed(breed) => mammal.breed.as_ref() == Some(breed),
+            Spec::IsDog => matches!(leaf, LeafRow::Dog(_)),
+            Spec::IsCat => matches!(leaf, LeafRow::Cat(_)),
+            Spec::IsHamster => matches!(leaf, LeafRow::Hamster(_)),
+            Spec::NameStartsWith(prefix) => pet.name.starts_with(prefix.as_str()),
+            Spec::And(a, b) => a.matches(leaf, mammal, pet) && b.matches(leaf, mammal, pet),
+            Spec::Or(a, b) => a.matches(leaf, mammal, pet) || b.matches(leaf, mammal, pet),
+            Spec::Not(spec) => !spec.matches(leaf, mammal, pet),
+        }
+    }
+}
+
+impl Not for Spec {
+    type Output = Spec;
+
+    fn not(self) -> Spec {
+        Spec::Not(Box::new(self))
+    }
+}
+
+impl PetState {
+    /// Finds all visible mammals satisfying `spec`.
+    ///
+    /// Mammals come in creation order, all leaf types interleaved like
+    /// [`get_all_mammals`](Self::get_all_mammals).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::Spec;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    ///
+    /// // Blond dogs (Sophie, Waterloo), plus anyone named S* (Shippen, Simba)
+    /// let spec = Spec::hair_color("blond")
+    ///     .and(Spec::is_dog())
+    ///     .or(Spec::name_starts_with("S"));
+    /// assert_eq!(ps.find(&spec).len(), 4);
+    ///
+    /// assert_eq!(ps.find(&!Spec::is_cat()).len(), 3);
+    /// ```
+    pub fn find(&self, spec: &Spec) -> Vec<Mammal> {
+        let mut matches = Vec::new();
+        self.visit_mammal_rows(|_, _, leaf, mammal, pet| {
+            if spec.matches(&leaf, mammal, pet) {
+                matches.push(leaf.compose(mammal, pet));
+            }
+        });
+        matches
+    }
+
+    /// Counts the visible mammals satisfying `spec`.
+    ///
+    /// Same result as `find(spec).len()`, but the predicate runs on the
+    /// component rows and nothing is composed or cloned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::Spec;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// assert_eq!(ps.count_where(&Spec::breed("shorthair")), 2);
+    /// assert_eq!(ps.count_where(&Spec::hair_color("blond").and(Spec::is_dog())), 2);
+    /// ```
+    pub fn count_where(&self, spec: &Spec) -> usize {
+        let mut count = 0;
+        self.visit_mammal_rows(|_, _, leaf, mammal, pet| {
+            if spec.matches(&leaf, mammal, pet) {
+                count += 1;
+            }
+        });
+        count
+    }
+}
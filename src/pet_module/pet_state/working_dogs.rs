@@ -0,0 +1,334 @@
+//! Working dogs: a fourth level below `Dog`.
+//!
+//! `ServiceDog` and `HerdingDog` are composed as
+//! Pet → Mammal → Dog → WorkingDog → leaf, showing that Class Table
+//! Inheritance scales past three tables by adding one component per level.
+//! A working dog keeps its `DogComponent`, so every dog query still sees it
+//! as a plain [`Dog`](crate::pet_module::Dog).
+//!
+//! # Component Mapping
+//!
+//! ```text
+//! WorkingDogComponent → WorkingDog table (dog_id, years_of_service)
+//! ServiceDogComponent → ServiceDog table (working_dog_id, assists_with)
+//! HerdingDogComponent → HerdingDog table (working_dog_id, livestock)
+//! ```
+
+use super::{DogComponent, MammalComponent, PetComponent, PetState, SequenceComponent, index_name};
+use crate::pet_module::{
+    DogData, HerdingDog, HerdingDogData, MammalData, ServiceDog, ServiceDogData, WorkingDog,
+    WorkingDogData,
+};
+use specs::prelude::*;
+use specs_derive::Component;
+
+/// Component representing the WorkingDog table (fourth level in hierarchy).
+///
+/// Maps to: `WorkingDog` table with columns (id, dog_id, years_of_service)
+#[derive(Component, Debug, Clone)]
+#[storage(DenseVecStorage)]
+pub(super) struct WorkingDogComponent {
+    years_of_service: u32,
+}
+
+impl From<&WorkingDogComponent> for WorkingDogData {
+    fn from(comp: &WorkingDogComponent) -> Self {
+        Self {
+            years_of_service: comp.years_of_service,
+        }
+    }
+}
+
+/// Component representing the ServiceDog table (leaf level in hierarchy).
+///
+/// Maps to: `ServiceDog` table with columns (id, working_dog_id, assists_with)
+#[derive(Component, Debug, Clone)]
+#[storage(DenseVecStorage)]
+pub(super) struct ServiceDogComponent {
+    assists_with: String,
+}
+
+impl From<&ServiceDogComponent> for ServiceDogData {
+    fn from(comp: &ServiceDogComponent) -> Self {
+        Self {
+            assists_with: comp.assists_with.clone(),
+        }
+    }
+}
+
+/// Component representing the HerdingDog table (leaf level in hierarchy).
+///
+/// Maps to: `HerdingDog` table with columns (id, working_dog_id, livestock)
+#[derive(Component, Debug, Clone)]
+#[storage(DenseVecStorage)]
+pub(super) struct HerdingDogComponent {
+    livestock: String,
+}
+
+impl From<&HerdingDogComponent> for HerdingDogData {
+    fn from(comp: &HerdingDogComponent) -> Self {
+        Self {
+            livestock: comp.livestock.clone(),
+        }
+    }
+}
+
+impl PetState {
+    /// Adds a service dog to the ECS world, creating an entity with five components.
+    ///
+    /// The entity carries `PetComponent`, `MammalComponent`, `DogComponent`,
+    /// `WorkingDogComponent` and `ServiceDogComponent`, one per table.
+    ///
+    /// # Returns
+    ///
+    /// Returns the UUID of the created dog for future reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the configured [`PetStateOptions`],
+    /// or if the [`IdGenerator`] returns an id already in use.
+    ///
+    /// [`PetStateOptions`]: crate::pet_module::PetStateOptions
+    /// [`IdGenerator`]: crate::pet_module::IdGenerator
+    pub fn create_service_dog(
+        &mut self,
+        name: impl Into<String>,
+        mammal: MammalData,
+        dog: DogData,
+        working_dog: WorkingDogData,
+        service: ServiceDogData,
+    ) -> String {
+        self.create_working_dog(
+            name.into(),
+            mammal,
+            dog,
+            working_dog,
+            ServiceDogComponent {
+                assists_with: service.assists_with,
+            },
+        )
+    }
+
+    /// Adds a herding dog to the ECS world, creating an entity with five components.
+    ///
+    /// Herding counterpart of [`create_service_dog`](Self::create_service_dog).
+    ///
+    /// # Returns
+    ///
+    /// Returns the UUID of the created dog for future reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or breed exceeds the configured [`PetStateOptions`],
+    /// or if the [`IdGenerator`] returns an id already in use.
+    ///
+    /// [`PetStateOptions`]: crate::pet_module::PetStateOptions
+    /// [`IdGenerator`]: crate::pet_module::IdGenerator
+    pub fn create_herding_dog(
+        &mut self,
+        name: impl Into<String>,
+        mammal: MammalData,
+        dog: DogData,
+        working_dog: WorkingDogData,
+        herding: HerdingDogData,
+    ) -> String {
+        self.create_working_dog(
+            name.into(),
+            mammal,
+            dog,
+            working_dog,
+            HerdingDogComponent {
+                livestock: herding.livestock,
+            },
+        )
+    }
+
+    /// Retrieves all working dogs, service and herding interleaved in creation order.
+    ///
+    /// # SQL Equivalent
+    ///
+    /// ```sql
+    /// SELECT * FROM Pet
+    /// INNER JOIN Mammal ON Pet.id = Mammal.pet_id
+    /// INNER JOIN Dog ON Mammal.id = Dog.mammal_id
+    /// INNER JOIN WorkingDog ON Dog.id = WorkingDog.dog_id
+    /// LEFT JOIN ServiceDog ON WorkingDog.id = ServiceDog.working_dog_id
+    /// LEFT JOIN HerdingDog ON WorkingDog.id = HerdingDog.working_dog_id
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ecs_inheritance_patterns::pet_module::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let mut ps = testing::sample_state();
+    /// ps.create_service_dog(
+    ///     "Buddy",
+    ///     MammalData { hair_color: "yellow".into(), breed: Some("labrador".into()), has_hair: true },
+    ///     DogData { tail_length: 11.0, num_commands_known: 40 },
+    ///     WorkingDogData { years_of_service: 3 },
+    ///     ServiceDogData { assists_with: "guide".into() },
+    /// );
+    ///
+    /// let working = ps.get_all_working_dogs();
+    /// assert_eq!(working.len(), 1);
+    /// assert_eq!(working[0].name(), "Buddy");
+    /// assert_eq!(ps.get_all_dogs().len(), 4);
+    /// ```
+    pub fn get_all_working_dogs(&self) -> Vec<WorkingDog> {
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_working_dogs = self.ecs.read_storage::<WorkingDogComponent>();
+        let fetched_service_dogs = self.ecs.read_storage::<ServiceDogComponent>();
+        let fetched_herding_dogs = self.ecs.read_storage::<HerdingDogComponent>();
+        let fetched_sequences = self.ecs.read_storage::<SequenceComponent>();
+        let hidden = self.hidden_entities();
+
+        let mut rows: Vec<_> = (
+            &fetched_pets,
+            &fetched_mammals,
+            &fetched_dogs,
+            &fetched_working_dogs,
+            (fetched_service_dogs.maybe(), fetched_herding_dogs.maybe()),
+            fetched_sequences.maybe(),
+            !&hidden,
+        )
+            .join()
+            .collect();
+        rows.sort_by_key(|(_, _, _, _, _, sequence, _)| sequence.map(|s| s.seq));
+
+        rows.into_iter()
+            .filter_map(|(pet, mammal, dog, working, leaf, _, _)| match leaf {
+                (Some(service), _) => Some(WorkingDog::Service(ServiceDog {
+                    pet: pet.into(),
+                    mammal: mammal.into(),
+                    dog: dog.into(),
+                    working_dog: working.into(),
+                    service_specific: service.into(),
+                })),
+                (None, Some(herding)) => Some(WorkingDog::Herding(HerdingDog {
+                    pet: pet.into(),
+                    mammal: mammal.into(),
+                    dog: dog.into(),
+                    working_dog: working.into(),
+                    herding_specific: herding.into(),
+                })),
+                (None, None) => None,
+            })
+            .collect()
+    }
+
+    /// Retrieves all service dogs in creation order.
+    pub fn get_all_service_dogs(&self) -> Vec<ServiceDog> {
+        self.get_all_working_dogs()
+            .into_iter()
+            .filter_map(|working| match working {
+                WorkingDog::Service(dog) => Some(dog),
+                WorkingDog::Herding(_) => None,
+            })
+            .collect()
+    }
+
+    /// Retrieves all herding dogs in creation order.
+    pub fn get_all_herding_dogs(&self) -> Vec<HerdingDog> {
+        self.get_all_working_dogs()
+            .into_iter()
+            .filter_map(|working| match working {
+                WorkingDog::Herding(dog) => Some(dog),
+                WorkingDog::Service(_) => None,
+            })
+            .collect()
+    }
+
+    /// Retrieves a specific working dog by UUID.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no pet has that UUID or the pet is not a working
+    /// dog, including a plain dog with no `WorkingDog` row.
+    pub fn get_working_dog_by_id(&self, uuid: &str) -> Option<WorkingDog> {
+        let entity = self.find_entity(uuid)?;
+        let fetched_pets = self.ecs.read_storage::<PetComponent>();
+        let fetched_mammals = self.ecs.read_storage::<MammalComponent>();
+        let fetched_dogs = self.ecs.read_storage::<DogComponent>();
+        let fetched_working_dogs = self.ecs.read_storage::<WorkingDogComponent>();
+
+        let pet = fetched_pets.get(entity)?.into();
+        let mammal = fetched_mammals.get(entity)?.into();
+        let dog = fetched_dogs.get(entity)?.into();
+        let working_dog = fetched_working_dogs.get(entity)?.into();
+
+        if let Some(service) = self.ecs.read_storage::<ServiceDogComponent>().get(entity) {
+            return Some(WorkingDog::Service(ServiceDog {
+                pet,
+                mammal,
+                dog,
+                working_dog,
+                service_specific: service.into(),
+            }));
+        }
+        let herding = self.ecs.read_storage::<HerdingDogComponent>();
+        Some(WorkingDog::Herding(HerdingDog {
+            pet,
+            mammal,
+            dog,
+            working_dog,
+            herding_specific: herding.get(entity)?.into(),
+        }))
+    }
+
+    /// Removes the WorkingDog row and its subtype rows from `entity`, if any.
+    pub(super) fn remove_working_dog_rows(&mut self, entity: Entity) {
+        self.ecs
+            .write_storage::<WorkingDogComponent>()
+            .remove(entity);
+        self.ecs
+            .write_storage::<ServiceDogComponent>()
+            .remove(entity);
+        self.ecs
+            .write_storage::<HerdingDogComponent>()
+            .remove(entity);
+    }
+
+    /// Builds a working dog entity with the given leaf component.
+    fn create_working_dog<C: Component + Send + Sync>(
+        &mut self,
+        name: String,
+        mammal: MammalData,
+        dog: DogData,
+        working_dog: WorkingDogData,
+        leaf: C,
+    ) -> String {
+        if let Err(err) = self.check_new_pet(&name, mammal.breed.as_deref()) {
+            panic!("{err}");
+        }
+        let uuid = self.next_free_id().unwrap_or_else(|err| panic!("{err}"));
+        let sequence = self.next_sequence();
+
+        let entity = self
+            .ecs
+            .create_entity()
+            .with(PetComponent {
+                uuid: uuid.clone(),
+                name: name.clone(),
+            })
+            .with(MammalComponent::new(
+                &mammal.hair_color,
+                mammal.breed,
+                mammal.has_hair,
+            ))
+            .with(DogComponent::from(dog))
+            .with(WorkingDogComponent {
+                years_of_service: working_dog.years_of_service,
+            })
+            .with(leaf)
+            .with(sequence)
+            .build();
+        self.by_uuid.insert(uuid.clone(), entity);
+        index_name(&mut self.by_name, name, entity);
+
+        uuid
+    }
+}
@@ -0,0 +1,72 @@
+//! Direct access to the underlying ECS world (advanced).
+//!
+//! The Gateway keeps `World` private so the domain API stays independent of
+//! the storage strategy. These closures are an escape hatch for power users
+//! who need custom joins or systems over components they registered
+//! themselves. They require the `world-access` feature; the crate re-exports
+//! `specs` under the same feature so callers use the matching version.
+
+use super::PetState;
+use specs::World;
+
+impl PetState {
+    /// Runs `f` with shared access to the ECS world.
+    ///
+    /// The built-in components are private to this crate, so joins over them
+    /// aren't possible from outside; entity and resource access, and any
+    /// components the caller registered, are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "world-access")] {
+    /// use ecs_inheritance_patterns::specs::prelude::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// let ps = testing::sample_state();
+    /// let live = ps.with_world(|world| world.entities().join().count());
+    /// assert_eq!(live, 5);
+    /// # }
+    /// ```
+    pub fn with_world<R>(&self, f: impl FnOnce(&World) -> R) -> R {
+        f(&self.ecs)
+    }
+
+    /// Runs `f` with exclusive access to the ECS world.
+    ///
+    /// Use it to register your own components and run systems over them.
+    /// Creating or deleting pet entities this way bypasses the state's UUID
+    /// and name indexes, so lookups like
+    /// [`get_mammal_by_id`](Self::get_mammal_by_id) won't see the change; use
+    /// the regular [`PetState`] methods for that.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "world-access")] {
+    /// use ecs_inheritance_patterns::specs::prelude::*;
+    /// use ecs_inheritance_patterns::testing;
+    ///
+    /// struct Visits(u32);
+    ///
+    /// impl Component for Visits {
+    ///     type Storage = VecStorage<Self>;
+    /// }
+    ///
+    /// let mut ps = testing::sample_state();
+    /// let total = ps.with_world_mut(|world| {
+    ///     world.register::<Visits>();
+    ///     let entities: Vec<Entity> = world.entities().join().collect();
+    ///     let mut visits = world.write_storage::<Visits>();
+    ///     for (n, entity) in entities.into_iter().enumerate() {
+    ///         visits.insert(entity, Visits(n as u32)).unwrap();
+    ///     }
+    ///     visits.join().map(|v| v.0).sum::<u32>()
+    /// });
+    /// assert_eq!(total, 10);
+    /// # }
+    /// ```
+    pub fn with_world_mut<R>(&mut self, f: impl FnOnce(&mut World) -> R) -> R {
+        f(&mut self.ecs)
+    }
+}
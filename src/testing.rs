@@ -0,0 +1,99 @@
+//! Prepared [`PetState`] fixtures for doctests and examples.
+//!
+//! Doc examples across the crate need a populated state far more often than
+//! they need to show how one is built. These helpers keep that setup to a
+//! single line and keep every example consistent as constructor signatures
+//! evolve.
+//!
+//! # Example
+//!
+//! ```
+//! use ecs_inheritance_patterns::testing;
+//!
+//! let ps = testing::sample_state();
+//! assert_eq!(ps.get_all_dogs().len(), 3);
+//! assert_eq!(ps.get_all_cats().len(), 2);
+//! ```
+
+use crate::pet_module::{Cat, Dog, PetState, SpeciesInfo};
+
+/// Hair colors cycled through by [`sample_state_with`].
+const HAIR_COLORS: [&str; 4] = ["gray", "blond", "black", "brown"];
+
+/// Returns the demonstration dataset used by the binary: three dogs and two cats.
+///
+/// | Name     | Species | Hair color | Breed      |
+/// |----------|---------|------------|------------|
+/// | Shippen  | Dog     | gray       | schnauzer  |
+/// | Sophie   | Dog     | blond      | schnauzer  |
+/// | Waterloo | Dog     | blond      | labrador   |
+/// | Berlioz  | Cat     | black      | shorthair  |
+/// | Simba    | Cat     | blond      | shorthair  |
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::testing;
+///
+/// let ps = testing::sample_state();
+/// assert_eq!(ps.get_mammals_by_hair_color("blond").len(), 3);
+/// ```
+pub fn sample_state() -> PetState {
+    let mut ps = PetState::new();
+
+    ps.create_dog("Shippen", "gray", "schnauzer", true, 2.0, 42);
+    ps.create_dog("Sophie", "blond", "schnauzer", true, 2.0, 56);
+    ps.create_dog("Waterloo", "blond", "labrador", true, 12.0, 4);
+    ps.create_cat("Berlioz", "black", "shorthair", true, true, false);
+    ps.create_cat("Simba", "blond", "shorthair", true, true, true);
+
+    ps
+}
+
+/// Returns a state holding `dogs` dogs followed by `cats` cats.
+///
+/// Pets are named `Dog 1`, `Dog 2`, …, `Cat 1`, `Cat 2`, … and cycle through
+/// a fixed palette of hair colors and each species'
+/// [`DEFAULT_BREEDS`](SpeciesInfo::DEFAULT_BREEDS), so the same arguments
+/// always produce the same data (apart from the generated UUIDs).
+///
+/// # Example
+///
+/// ```
+/// use ecs_inheritance_patterns::testing;
+///
+/// let ps = testing::sample_state_with(4, 1);
+/// assert_eq!(ps.get_all_dogs().len(), 4);
+/// assert_eq!(ps.get_all_cats()[0].pet.name, "Cat 1");
+/// ```
+pub fn sample_state_with(dogs: usize, cats: usize) -> PetState {
+    let mut ps = PetState::new();
+
+    for i in 0..dogs {
+        let hair_color = HAIR_COLORS[i % HAIR_COLORS.len()];
+        let breed = Dog::DEFAULT_BREEDS[i % Dog::DEFAULT_BREEDS.len()];
+        ps.create_dog(
+            format!("Dog {}", i + 1),
+            hair_color,
+            breed,
+            true,
+            (i % 15) as f64 + 1.0,
+            (i % 50) as i32,
+        );
+    }
+
+    for i in 0..cats {
+        let hair_color = HAIR_COLORS[i % HAIR_COLORS.len()];
+        let breed = Cat::DEFAULT_BREEDS[i % Cat::DEFAULT_BREEDS.len()];
+        ps.create_cat(
+            format!("Cat {}", i + 1),
+            hair_color,
+            breed,
+            true,
+            i % 3 == 0,
+            i % 2 == 0,
+        );
+    }
+
+    ps
+}